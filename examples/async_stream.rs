@@ -0,0 +1,55 @@
+use dingtalk_sdk::{
+    AppCredentials, Client, StreamAck, StreamFrame, StreamHandler, StreamSubscription, TextMessage,
+};
+use serde_json::Value;
+
+struct EchoHandler {
+    client: Client,
+}
+
+impl StreamHandler for EchoHandler {
+    async fn on_callback(&self, frame: StreamFrame) -> StreamAck {
+        if let Ok(message) = frame.robot_message() {
+            let text = message.text().unwrap_or_default().trim().to_string();
+            if let Ok(session) = self.client.session_webhook_from_callback(&message)
+                && let Err(error) = session
+                    .send(&TextMessage::new(format!("echo: {text}")).into())
+                    .await
+            {
+                eprintln!("Reply failed: {error}");
+            }
+        }
+        StreamAck::callback(Value::Null)
+    }
+
+    async fn on_event(&self, frame: StreamFrame) -> StreamAck {
+        println!(
+            "Event {}: {}",
+            frame.headers.event_type.as_deref().unwrap_or("unknown"),
+            frame.data
+        );
+        StreamAck::success()
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let (Ok(appkey), Ok(appsecret)) = (
+        std::env::var("DINGTALK_APP_KEY"),
+        std::env::var("DINGTALK_APP_SECRET"),
+    ) else {
+        eprintln!("Set DINGTALK_APP_KEY and DINGTALK_APP_SECRET to run this example.");
+        return Ok(());
+    };
+
+    let client = Client::builder().build()?;
+    client
+        .stream(AppCredentials::new(appkey, appsecret))
+        .subscribe(StreamSubscription::robot_messages())
+        .subscribe(StreamSubscription::all_events())
+        .run(EchoHandler {
+            client: client.clone(),
+        })
+        .await?;
+    Ok(())
+}
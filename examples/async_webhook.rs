@@ -23,6 +23,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         )
         .await?;
 
-    println!("{response}");
+    println!("{}", response.raw_body);
     Ok(())
 }
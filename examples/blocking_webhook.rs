@@ -20,6 +20,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         Some(false),
     )?;
 
-    println!("{response}");
+    println!("{}", response.raw_body);
     Ok(())
 }
@@ -1,28 +1,55 @@
+use std::collections::{BTreeSet, HashMap};
+use std::future::Future;
+
+use futures_util::{Stream, StreamExt, TryStreamExt, stream};
+
 use serde::de::DeserializeOwned;
 
-use super::is_private_conversation;
+use super::{
+    OrgTreeWalker, ReplyTarget, async_org::DEFAULT_CONCURRENCY, pagination::cursor_stream,
+};
 
 use crate::{
-    auth::AppCredentials,
+    auth::TokenCredentials,
     client::async_client::Client,
     error::{Error, Result},
     transport::{
-        DEFAULT_MSG_KEY, parse_approval_create_response, parse_approval_get_response,
-        parse_get_token_response, parse_standard_api_text_response, parse_topapi_result_response,
-        parse_topapi_unit_response,
+        AccessTokenPayload, DEFAULT_MSG_KEY, TokenEndpoint, is_invalid_token_error,
+        parse_approval_create_response, parse_approval_get_response, parse_get_token_response,
+        parse_json_response, parse_standard_api_response, parse_standard_json_response,
+        parse_topapi_result_response, parse_topapi_unit_response,
     },
     types::{
         ApprovalCreateProcessInstanceRequest, ApprovalListProcessInstanceIdsRequest,
         ApprovalListProcessInstanceIdsResult, ApprovalProcessInstance,
-        ApprovalTerminateProcessInstanceRequest, ContactCreateDepartmentRequest,
-        ContactCreateDepartmentResult, ContactCreateUserRequest, ContactCreateUserResult,
-        ContactDeleteDepartmentRequest, ContactDeleteUserRequest, ContactDepartment,
-        ContactGetDepartmentRequest, ContactGetUserByMobileRequest, ContactGetUserByUnionIdRequest,
-        ContactGetUserRequest, ContactListSubDepartmentIdsRequest,
-        ContactListSubDepartmentIdsResult, ContactListSubDepartmentsRequest,
-        ContactListSubDepartmentsResult, ContactListUsersRequest, ContactListUsersResult,
-        ContactUpdateDepartmentRequest, ContactUpdateUserRequest, ContactUser,
-        internal::{GroupMessageRequest, MsgParam, OtoMessageRequest},
+        ApprovalTerminateProcessInstanceRequest, ContactAdmin, ContactAdminScope,
+        ContactCountUsersRequest, ContactCountUsersResult, ContactCreateDepartmentRequest,
+        ContactCreateDepartmentResult, ContactCreateExternalContactRequest,
+        ContactCreateUserRequest, ContactCreateUserResult, ContactDeleteDepartmentRequest,
+        ContactDeleteExternalContactRequest, ContactDeleteUserRequest, ContactDepartment,
+        ContactEmpLeaveRecord, ContactEmpLeaveRecordsResult, ContactExternal,
+        ContactExternalLabelGroup, ContactGetAdminScopeRequest, ContactGetDepartmentRequest,
+        ContactGetExternalContactRequest, ContactGetUserByMobileRequest,
+        ContactGetUserByUnionIdRequest, ContactGetUserRequest, ContactListEmpLeaveRecordsRequest,
+        ContactListExternalContactsRequest, ContactListExternalLabelGroupsRequest,
+        ContactListInactiveUsersRequest, ContactListInactiveUsersResult,
+        ContactListParentDepartmentsByDeptRequest, ContactListParentDepartmentsByUserRequest,
+        ContactListSimpleUsersRequest, ContactListSimpleUsersResult,
+        ContactListSubDepartmentIdsRequest, ContactListSubDepartmentIdsResult,
+        ContactListSubDepartmentsRequest, ContactListSubDepartmentsResult,
+        ContactListUserIdsRequest, ContactListUserIdsResult, ContactListUsersRequest,
+        ContactListUsersResult, ContactSimpleUser, ContactUpdateDepartmentRequest,
+        ContactUpdateExternalContactRequest, ContactUpdateUserRequest, ContactUser,
+        GroupMessageSendResponse, JsapiTicket, OtoBatchSendResponse, ReplyMessageResponse,
+        RobotCallbackMessage, Role, RoleAssignmentRequest, RoleGetRoleGroupRequest,
+        RoleGetRoleRequest, RoleGroup, RoleListRequest, RoleListResult, RoleListUsersRequest,
+        RoleListUsersResult, RoleUser, UserIdentity,
+        internal::{
+            CorpAccessTokenRequest, ExternalContactCreateResponse, ExternalContactPayload,
+            GroupMessageRequest, MsgParam, OAuth2AccessTokenRequest, OtoMessageRequest,
+            ParentDepartmentIdsResult, RawBodyResponse, RoleGroupResponse, RoleResponse,
+            TopApiResultsResponse, UserInfoByCodeRequest, UserParentDepartmentsResult,
+        },
     },
 };
 
@@ -30,47 +57,125 @@ use crate::{
 #[derive(Clone)]
 pub struct EnterpriseService {
     client: Client,
-    credentials: AppCredentials,
+    credentials: TokenCredentials,
+    cache_key: String,
     robot_code: String,
 }
 
 impl EnterpriseService {
     pub(crate) fn new(
         client: Client,
-        appkey: impl Into<String>,
-        appsecret: impl Into<String>,
+        credentials: TokenCredentials,
         robot_code: impl Into<String>,
     ) -> Self {
         Self {
             client,
-            credentials: AppCredentials::new(appkey, appsecret),
+            cache_key: credentials.cache_key(),
+            credentials,
             robot_code: robot_code.into(),
         }
     }
 
     /// Retrieves enterprise access token and refreshes cache when needed.
+    ///
+    /// Concurrent refreshes for the same credentials share one token request
+    /// and its result, including a failure.
     pub async fn get_access_token(&self) -> Result<String> {
-        if let Some(token) = self.client.cached_access_token(&self.credentials) {
+        if let Some(token) = self.client.cached_access_token(&self.cache_key) {
             return Ok(token);
         }
 
-        let endpoint = self.client.webhook_endpoint(&["gettoken"])?;
-        let payload = parse_get_token_response(
-            self.client
-                .webhook_http()
-                .get(endpoint.as_str())
-                .query_pair("appkey", self.credentials.appkey().to_string())
-                .query_pair("appsecret", self.credentials.appsecret().to_string())
-                .send_response()
-                .await?,
-            self.client.body_snippet(),
-        )?;
-        let access_token = payload.token;
+        let refresh = self.client.join_token_refresh(&self.cache_key);
+        let result = refresh
+            .get_or_init(|| async {
+                if let Some(token) = self.client.cached_access_token(&self.cache_key) {
+                    return Ok(token);
+                }
+
+                let payload = self.fetch_access_token().await?;
+                self.client.store_access_token(
+                    &self.cache_key,
+                    payload.token.clone(),
+                    payload.expires_in,
+                );
+                Ok(payload.token)
+            })
+            .await;
+
+        match result {
+            Ok(token) => Ok(token.clone()),
+            Err(error) => Err(error.duplicate()),
+        }
+    }
 
-        self.client
-            .store_access_token(&self.credentials, access_token.clone(), payload.expires_in);
+    async fn fetch_access_token(&self) -> Result<AccessTokenPayload> {
+        let response = match (&self.credentials, self.client.token_endpoint()) {
+            (TokenCredentials::App(credentials), TokenEndpoint::Legacy) => {
+                let endpoint = self.client.webhook_endpoint(&["gettoken"])?;
+                self.client
+                    .webhook_http()
+                    .get(endpoint.as_str())
+                    .query_pair("appkey", credentials.appkey().to_string())
+                    .query_pair("appsecret", credentials.appsecret().to_string())
+                    .send_response()
+                    .await?
+            }
+            (TokenCredentials::App(credentials), TokenEndpoint::OAuth2) => {
+                let endpoint =
+                    self.client
+                        .enterprise_endpoint(&["v1.0", "oauth2", "accessToken"])?;
+                self.client
+                    .enterprise_http()
+                    .post(endpoint.as_str())
+                    .json(&OAuth2AccessTokenRequest {
+                        app_key: credentials.appkey(),
+                        app_secret: credentials.appsecret(),
+                    })?
+                    .send_response()
+                    .await?
+            }
+            (TokenCredentials::Corp { suite, corp_id }, _) => {
+                let suite_ticket = suite.suite_ticket().ok_or_else(|| Error::InvalidConfig {
+                    message: format!("No suite ticket available for suite {}", suite.suite_key()),
+                    source: None,
+                })?;
+                let endpoint =
+                    self.client
+                        .enterprise_endpoint(&["v1.0", "oauth2", "corpAccessToken"])?;
+                self.client
+                    .enterprise_http()
+                    .post(endpoint.as_str())
+                    .json(&CorpAccessTokenRequest {
+                        suite_key: suite.suite_key(),
+                        suite_secret: suite.suite_secret(),
+                        auth_corp_id: corp_id,
+                        suite_ticket: &suite_ticket,
+                    })?
+                    .send_response()
+                    .await?
+            }
+        };
+        parse_get_token_response(response, self.client.body_snippet())
+    }
 
-        Ok(access_token)
+    /// Runs `call` with an access token, retrying once with a fresh token when
+    /// DingTalk rejects the cached one.
+    async fn with_access_token<T, F, Fut>(&self, call: F) -> Result<T>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let access_token = self.get_access_token().await?;
+        match call(access_token.clone()).await {
+            Err(error)
+                if self.client.retry_on_invalid_token() && is_invalid_token_error(&error) =>
+            {
+                self.client
+                    .invalidate_access_token(&self.cache_key, &access_token);
+                call(self.get_access_token().await?).await
+            }
+            result => result,
+        }
     }
 
     async fn post_topapi_result<T, B>(&self, segments: &[&str], body: &B) -> Result<T>
@@ -78,56 +183,111 @@ impl EnterpriseService {
         T: DeserializeOwned,
         B: serde::Serialize + ?Sized,
     {
-        let access_token = self.get_access_token().await?;
         let endpoint = self.client.webhook_endpoint(segments)?;
-        parse_topapi_result_response(
-            self.client
-                .webhook_http()
-                .post(endpoint.as_str())
-                .query_pair("access_token", access_token)
-                .json(body)?
-                .send_response()
-                .await?,
-            self.client.body_snippet(),
-        )
+        let endpoint = &endpoint;
+        self.with_access_token(|access_token| async move {
+            parse_topapi_result_response(
+                self.client
+                    .webhook_http()
+                    .post(endpoint.as_str())
+                    .query_pair("access_token", access_token)
+                    .json(body)?
+                    .send_response()
+                    .await?,
+                self.client.body_snippet(),
+            )
+        })
+        .await
+    }
+
+    async fn resolve_department_paths(
+        &self,
+        paths: Vec<Vec<i64>>,
+    ) -> Result<Vec<Vec<ContactDepartment>>> {
+        let dept_ids: BTreeSet<i64> = paths.iter().flatten().copied().collect();
+        let departments: HashMap<i64, ContactDepartment> = stream::iter(dept_ids)
+            .map(|dept_id| async move {
+                let department = self
+                    .contact_get_department(ContactGetDepartmentRequest::new(dept_id))
+                    .await?;
+                Ok::<_, Error>((dept_id, department))
+            })
+            .buffer_unordered(DEFAULT_CONCURRENCY)
+            .try_collect()
+            .await?;
+        Ok(paths
+            .into_iter()
+            .map(|path| {
+                path.iter()
+                    .filter_map(|dept_id| departments.get(dept_id).cloned())
+                    .collect()
+            })
+            .collect())
+    }
+
+    async fn post_topapi_json<T, B>(&self, segments: &[&str], body: &B) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: serde::Serialize + ?Sized,
+    {
+        let endpoint = self.client.webhook_endpoint(segments)?;
+        let endpoint = &endpoint;
+        self.with_access_token(|access_token| async move {
+            parse_standard_json_response(
+                self.client
+                    .webhook_http()
+                    .post(endpoint.as_str())
+                    .query_pair("access_token", access_token)
+                    .json(body)?
+                    .send_response()
+                    .await?,
+                self.client.body_snippet(),
+            )
+        })
+        .await
     }
 
     async fn post_topapi_unit<B>(&self, segments: &[&str], body: &B) -> Result<()>
     where
         B: serde::Serialize + ?Sized,
     {
-        let access_token = self.get_access_token().await?;
         let endpoint = self.client.webhook_endpoint(segments)?;
-        parse_topapi_unit_response(
-            self.client
-                .webhook_http()
-                .post(endpoint.as_str())
-                .query_pair("access_token", access_token)
-                .json(body)?
-                .send_response()
-                .await?,
-            self.client.body_snippet(),
-        )
+        let endpoint = &endpoint;
+        self.with_access_token(|access_token| async move {
+            parse_topapi_unit_response(
+                self.client
+                    .webhook_http()
+                    .post(endpoint.as_str())
+                    .query_pair("access_token", access_token)
+                    .json(body)?
+                    .send_response()
+                    .await?,
+                self.client.body_snippet(),
+            )
+        })
+        .await
     }
 
-    async fn send_enterprise_message<T: serde::Serialize + ?Sized>(
-        &self,
-        segments: &[&str],
-        payload: &T,
-    ) -> Result<String> {
-        let access_token = self.get_access_token().await?;
+    async fn send_enterprise_message<T, R>(&self, segments: &[&str], payload: &T) -> Result<R>
+    where
+        T: serde::Serialize + ?Sized,
+        R: RawBodyResponse,
+    {
         let endpoint = self.client.enterprise_endpoint(segments)?;
-
-        parse_standard_api_text_response(
-            self.client
-                .enterprise_http()
-                .post(endpoint.as_str())
-                .try_header("x-acs-dingtalk-access-token", &access_token)?
-                .json(payload)?
-                .send_response()
-                .await?,
-            self.client.body_snippet(),
-        )
+        let endpoint = &endpoint;
+        self.with_access_token(|access_token| async move {
+            parse_standard_api_response(
+                self.client
+                    .enterprise_http()
+                    .post(endpoint.as_str())
+                    .try_header("x-acs-dingtalk-access-token", &access_token)?
+                    .json(payload)?
+                    .send_response()
+                    .await?,
+                self.client.body_snippet(),
+            )
+        })
+        .await
     }
 
     /// Sends a group message to a conversation.
@@ -136,7 +296,7 @@ impl EnterpriseService {
         open_conversation_id: &str,
         title: &str,
         text: &str,
-    ) -> Result<String> {
+    ) -> Result<GroupMessageSendResponse> {
         let request = GroupMessageRequest {
             msg_param: MsgParam {
                 title: title.to_string(),
@@ -152,7 +312,12 @@ impl EnterpriseService {
     }
 
     /// Sends a one-to-one message to a user.
-    pub async fn send_oto_message(&self, user_id: &str, title: &str, text: &str) -> Result<String> {
+    pub async fn send_oto_message(
+        &self,
+        user_id: &str,
+        title: &str,
+        text: &str,
+    ) -> Result<OtoBatchSendResponse> {
         let request = OtoMessageRequest {
             msg_param: MsgParam {
                 title: title.to_string(),
@@ -191,6 +356,36 @@ impl EnterpriseService {
             .await
     }
 
+    /// Resolves the user behind an in-app free-login (JSAPI `requestAuthCode`) code.
+    pub async fn get_user_info_by_code(&self, code: &str) -> Result<UserIdentity> {
+        self.post_topapi_result(
+            &["topapi", "v2", "user", "getuserinfo"],
+            &UserInfoByCodeRequest { code },
+        )
+        .await
+    }
+
+    /// Retrieves the JSAPI ticket used to sign `dd.config`.
+    ///
+    /// Tickets are valid for `expires_in` seconds and should be cached by the caller;
+    /// see [`jsapi_signature`](crate::jsapi_signature).
+    pub async fn get_jsapi_ticket(&self) -> Result<JsapiTicket> {
+        let endpoint = self.client.webhook_endpoint(&["get_jsapi_ticket"])?;
+        let endpoint = &endpoint;
+        self.with_access_token(|access_token| async move {
+            parse_standard_json_response(
+                self.client
+                    .webhook_http()
+                    .get(endpoint.as_str())
+                    .query_pair("access_token", access_token)
+                    .send_response()
+                    .await?,
+                self.client.body_snippet(),
+            )
+        })
+        .await
+    }
+
     /// Lists users in a department.
     pub async fn contact_list_users(
         &self,
@@ -200,6 +395,137 @@ impl EnterpriseService {
             .await
     }
 
+    /// Streams every user of a department, following `next_cursor` until exhausted.
+    ///
+    /// Each page is fetched lazily; the stream ends after the first error.
+    pub fn contact_list_users_stream(
+        &self,
+        dept_id: i64,
+        page_size: i64,
+    ) -> impl Stream<Item = Result<ContactUser>> + '_ {
+        cursor_stream(
+            ContactListUsersRequest::new(dept_id, 0, page_size),
+            move |request| self.contact_list_users(request),
+        )
+    }
+
+    /// Lists the id and name of users in a department.
+    pub async fn contact_list_simple_users(
+        &self,
+        request: ContactListSimpleUsersRequest,
+    ) -> Result<ContactListSimpleUsersResult> {
+        self.post_topapi_result(&["topapi", "user", "listsimple"], &request)
+            .await
+    }
+
+    /// Streams the id and name of every user of a department.
+    ///
+    /// Each page is fetched lazily; the stream ends after the first error.
+    pub fn contact_list_simple_users_stream(
+        &self,
+        dept_id: i64,
+        page_size: i64,
+    ) -> impl Stream<Item = Result<ContactSimpleUser>> + '_ {
+        cursor_stream(
+            ContactListSimpleUsersRequest::new(dept_id, 0, page_size),
+            move |request| self.contact_list_simple_users(request),
+        )
+    }
+
+    /// Lists the user ids of a department.
+    pub async fn contact_list_user_ids(
+        &self,
+        request: ContactListUserIdsRequest,
+    ) -> Result<ContactListUserIdsResult> {
+        self.post_topapi_result(&["topapi", "user", "listid"], &request)
+            .await
+    }
+
+    /// Counts the users of the organization.
+    pub async fn contact_count_users(
+        &self,
+        request: ContactCountUsersRequest,
+    ) -> Result<ContactCountUsersResult> {
+        self.post_topapi_result(&["topapi", "user", "count"], &request)
+            .await
+    }
+
+    /// Lists the organization administrators.
+    pub async fn contact_list_admins(&self) -> Result<Vec<ContactAdmin>> {
+        self.post_topapi_result(&["topapi", "user", "listadmin"], &serde_json::json!({}))
+            .await
+    }
+
+    /// Gets the departments a sub-administrator manages.
+    pub async fn contact_get_admin_scope(
+        &self,
+        request: ContactGetAdminScopeRequest,
+    ) -> Result<ContactAdminScope> {
+        self.post_topapi_json(&["topapi", "user", "get_admin_scope"], &request)
+            .await
+    }
+
+    /// Lists users who did not (or, with `is_active`, did) use DingTalk on a day.
+    pub async fn contact_list_inactive_users(
+        &self,
+        request: ContactListInactiveUsersRequest,
+    ) -> Result<ContactListInactiveUsersResult> {
+        self.post_topapi_result(&["topapi", "inactive", "user", "v2", "get"], &request)
+            .await
+    }
+
+    /// Streams the ids of every user matching `request`, starting at its offset.
+    pub fn contact_list_inactive_users_stream(
+        &self,
+        request: ContactListInactiveUsersRequest,
+    ) -> impl Stream<Item = Result<String>> + '_ {
+        cursor_stream(request, move |request| {
+            self.contact_list_inactive_users(request)
+        })
+    }
+
+    /// Lists employees who resigned within a time range.
+    pub async fn contact_list_emp_leave_records(
+        &self,
+        request: ContactListEmpLeaveRecordsRequest,
+    ) -> Result<ContactEmpLeaveRecordsResult> {
+        let endpoint = self
+            .client
+            .enterprise_endpoint(&["v1.0", "contact", "empLeaveRecords"])?;
+        let endpoint = &endpoint;
+        let request = &request;
+        self.with_access_token(|access_token| async move {
+            parse_json_response(
+                self.client
+                    .enterprise_http()
+                    .get(endpoint.as_str())
+                    .try_header("x-acs-dingtalk-access-token", &access_token)?
+                    .query(request)?
+                    .send_response()
+                    .await?,
+                self.client.body_snippet(),
+            )
+        })
+        .await
+    }
+
+    /// Streams every resigned employee record matching `request`, following
+    /// `nextToken` until exhausted.
+    pub fn contact_list_emp_leave_records_stream(
+        &self,
+        request: ContactListEmpLeaveRecordsRequest,
+    ) -> impl Stream<Item = Result<ContactEmpLeaveRecord>> + '_ {
+        cursor_stream(request, move |request| {
+            self.contact_list_emp_leave_records(request)
+        })
+    }
+
+    /// Creates a walker that snapshots the department subtree under `root_dept_id`.
+    #[must_use]
+    pub fn org_tree_walker(&self, root_dept_id: i64) -> OrgTreeWalker {
+        OrgTreeWalker::new(self.clone(), root_dept_id)
+    }
+
     /// Creates a user.
     pub async fn contact_create_user(
         &self,
@@ -248,6 +574,66 @@ impl EnterpriseService {
             .await
     }
 
+    /// Lists the ancestor chain of a department, from the department itself up to the root.
+    pub async fn contact_list_parent_department_ids(
+        &self,
+        request: ContactListParentDepartmentsByDeptRequest,
+    ) -> Result<Vec<i64>> {
+        let result: ParentDepartmentIdsResult = self
+            .post_topapi_result(
+                &["topapi", "v2", "department", "listparentbydept"],
+                &request,
+            )
+            .await?;
+        Ok(result.parent_id_list)
+    }
+
+    /// Lists one ancestor chain per department the user belongs to, each from the
+    /// user's department up to the root.
+    pub async fn contact_list_user_parent_department_ids(
+        &self,
+        request: ContactListParentDepartmentsByUserRequest,
+    ) -> Result<Vec<Vec<i64>>> {
+        let result: UserParentDepartmentsResult = self
+            .post_topapi_result(
+                &["topapi", "v2", "department", "listparentbyuser"],
+                &request,
+            )
+            .await?;
+        Ok(result
+            .parent_list
+            .into_iter()
+            .map(|path| path.parent_dept_id_list)
+            .collect())
+    }
+
+    /// Resolves the ancestor chain of a department into department details, from the
+    /// department itself up to the root.
+    pub async fn contact_department_path(&self, dept_id: i64) -> Result<Vec<ContactDepartment>> {
+        let path = self
+            .contact_list_parent_department_ids(ContactListParentDepartmentsByDeptRequest::new(
+                dept_id,
+            ))
+            .await?;
+        let mut paths = self.resolve_department_paths(vec![path]).await?;
+        Ok(paths.pop().unwrap_or_default())
+    }
+
+    /// Resolves every department chain of a user into department details.
+    ///
+    /// Departments shared between chains are fetched once.
+    pub async fn contact_user_department_paths(
+        &self,
+        userid: &str,
+    ) -> Result<Vec<Vec<ContactDepartment>>> {
+        let paths = self
+            .contact_list_user_parent_department_ids(
+                ContactListParentDepartmentsByUserRequest::new(userid),
+            )
+            .await?;
+        self.resolve_department_paths(paths).await
+    }
+
     /// Creates a department.
     pub async fn contact_create_department(
         &self,
@@ -275,25 +661,165 @@ impl EnterpriseService {
             .await
     }
 
+    /// Lists external contacts.
+    pub async fn contact_list_external_contacts(
+        &self,
+        request: ContactListExternalContactsRequest,
+    ) -> Result<Vec<ContactExternal>> {
+        let response: TopApiResultsResponse<ContactExternal> = self
+            .post_topapi_json(&["topapi", "extcontact", "list"], &request)
+            .await?;
+        Ok(response.results)
+    }
+
+    /// Gets external contact details.
+    pub async fn contact_get_external_contact(
+        &self,
+        request: ContactGetExternalContactRequest,
+    ) -> Result<ContactExternal> {
+        self.post_topapi_result(&["topapi", "extcontact", "get"], &request)
+            .await
+    }
+
+    /// Creates an external contact and returns its user id.
+    pub async fn contact_create_external_contact(
+        &self,
+        request: ContactCreateExternalContactRequest,
+    ) -> Result<String> {
+        let response: ExternalContactCreateResponse = self
+            .post_topapi_json(
+                &["topapi", "extcontact", "create"],
+                &ExternalContactPayload { contact: &request },
+            )
+            .await?;
+        Ok(response.userid)
+    }
+
+    /// Updates an external contact.
+    pub async fn contact_update_external_contact(
+        &self,
+        request: ContactUpdateExternalContactRequest,
+    ) -> Result<()> {
+        self.post_topapi_unit(
+            &["topapi", "extcontact", "update"],
+            &ExternalContactPayload { contact: &request },
+        )
+        .await
+    }
+
+    /// Deletes an external contact.
+    pub async fn contact_delete_external_contact(
+        &self,
+        request: ContactDeleteExternalContactRequest,
+    ) -> Result<()> {
+        self.post_topapi_unit(&["topapi", "extcontact", "delete"], &request)
+            .await
+    }
+
+    /// Lists external contact label groups and their labels.
+    pub async fn contact_list_external_label_groups(
+        &self,
+        request: ContactListExternalLabelGroupsRequest,
+    ) -> Result<Vec<ContactExternalLabelGroup>> {
+        let response: TopApiResultsResponse<ContactExternalLabelGroup> = self
+            .post_topapi_json(&["topapi", "extcontact", "listlabelgroups"], &request)
+            .await?;
+        Ok(response.results)
+    }
+
+    /// Lists role groups and their roles.
+    pub async fn role_list(&self, request: RoleListRequest) -> Result<RoleListResult> {
+        self.post_topapi_result(&["topapi", "role", "list"], &request)
+            .await
+    }
+
+    /// Streams every role group, following the offset until exhausted.
+    ///
+    /// Each page is fetched lazily; the stream ends after the first error.
+    pub fn role_list_stream(&self, page_size: i64) -> impl Stream<Item = Result<RoleGroup>> + '_ {
+        cursor_stream(RoleListRequest::new(0, page_size), move |request| {
+            self.role_list(request)
+        })
+    }
+
+    /// Gets a role group and its roles.
+    pub async fn role_get_role_group(&self, request: RoleGetRoleGroupRequest) -> Result<RoleGroup> {
+        let response: RoleGroupResponse = self
+            .post_topapi_json(&["topapi", "role", "getrolegroup"], &request)
+            .await?;
+        let mut group = response.role_group;
+        group.group_id = request.group_id;
+        Ok(group)
+    }
+
+    /// Gets a role.
+    pub async fn role_get_role(&self, request: RoleGetRoleRequest) -> Result<Role> {
+        let response: RoleResponse = self
+            .post_topapi_json(&["topapi", "role", "getrole"], &request)
+            .await?;
+        let mut role = response.role;
+        role.id = request.role_id;
+        Ok(role)
+    }
+
+    /// Lists the users that hold a role.
+    pub async fn role_list_users(
+        &self,
+        request: RoleListUsersRequest,
+    ) -> Result<RoleListUsersResult> {
+        self.post_topapi_result(&["topapi", "role", "simplelist"], &request)
+            .await
+    }
+
+    /// Streams every user that holds a role, following the offset until exhausted.
+    ///
+    /// Each page is fetched lazily; the stream ends after the first error.
+    pub fn role_list_users_stream(
+        &self,
+        role_id: i64,
+        page_size: i64,
+    ) -> impl Stream<Item = Result<RoleUser>> + '_ {
+        cursor_stream(
+            RoleListUsersRequest::new(role_id, 0, page_size),
+            move |request| self.role_list_users(request),
+        )
+    }
+
+    /// Adds roles to users.
+    pub async fn role_add_roles_for_users(&self, request: RoleAssignmentRequest) -> Result<()> {
+        self.post_topapi_unit(&["topapi", "role", "addrolesforemps"], &request)
+            .await
+    }
+
+    /// Removes roles from users.
+    pub async fn role_remove_roles_for_users(&self, request: RoleAssignmentRequest) -> Result<()> {
+        self.post_topapi_unit(&["topapi", "role", "removerolesforemps"], &request)
+            .await
+    }
+
     /// Creates an approval process instance and returns its id.
     pub async fn approval_create_process_instance(
         &self,
         request: ApprovalCreateProcessInstanceRequest,
     ) -> Result<String> {
-        let access_token = self.get_access_token().await?;
         let endpoint = self
             .client
             .webhook_endpoint(&["topapi", "processinstance", "create"])?;
-        parse_approval_create_response(
-            self.client
-                .webhook_http()
-                .post(endpoint.as_str())
-                .query_pair("access_token", access_token)
-                .json(&request)?
-                .send_response()
-                .await?,
-            self.client.body_snippet(),
-        )
+        let endpoint = &endpoint;
+        let request = &request;
+        self.with_access_token(|access_token| async move {
+            parse_approval_create_response(
+                self.client
+                    .webhook_http()
+                    .post(endpoint.as_str())
+                    .query_pair("access_token", access_token)
+                    .json(request)?
+                    .send_response()
+                    .await?,
+                self.client.body_snippet(),
+            )
+        })
+        .await
     }
 
     /// Gets approval process instance details.
@@ -301,23 +827,27 @@ impl EnterpriseService {
         &self,
         process_instance_id: &str,
     ) -> Result<ApprovalProcessInstance> {
-        let access_token = self.get_access_token().await?;
         let endpoint = self
             .client
             .webhook_endpoint(&["topapi", "processinstance", "get"])?;
         let request = serde_json::json!({
             "process_instance_id": process_instance_id
         });
-        parse_approval_get_response(
-            self.client
-                .webhook_http()
-                .post(endpoint.as_str())
-                .query_pair("access_token", access_token)
-                .json(&request)?
-                .send_response()
-                .await?,
-            self.client.body_snippet(),
-        )
+        let endpoint = &endpoint;
+        let request = &request;
+        self.with_access_token(|access_token| async move {
+            parse_approval_get_response(
+                self.client
+                    .webhook_http()
+                    .post(endpoint.as_str())
+                    .query_pair("access_token", access_token)
+                    .json(request)?
+                    .send_response()
+                    .await?,
+                self.client.body_snippet(),
+            )
+        })
+        .await
     }
 
     /// Lists approval process instance ids.
@@ -329,6 +859,17 @@ impl EnterpriseService {
             .await
     }
 
+    /// Streams every approval process instance id matching `request`, starting at
+    /// its cursor and following `next_cursor` until exhausted.
+    pub fn approval_list_process_instance_ids_stream(
+        &self,
+        request: ApprovalListProcessInstanceIdsRequest,
+    ) -> impl Stream<Item = Result<String>> + '_ {
+        cursor_stream(request, move |request| {
+            self.approval_list_process_instance_ids(request)
+        })
+    }
+
     /// Terminates an approval process instance.
     pub async fn approval_terminate_process_instance(
         &self,
@@ -348,48 +889,63 @@ impl EnterpriseService {
         data: &serde_json::Value,
         title: &str,
         text: &str,
-    ) -> Result<String> {
+    ) -> Result<ReplyMessageResponse> {
+        self.reply_to(ReplyTarget::from_value(data)?, title, text)
+            .await
+    }
+
+    /// Replies to a typed incoming callback message.
+    ///
+    /// Routing is the same as [`Self::reply_message`].
+    pub async fn reply_callback_message(
+        &self,
+        message: &RobotCallbackMessage,
+        title: &str,
+        text: &str,
+    ) -> Result<ReplyMessageResponse> {
+        self.reply_to(ReplyTarget::from_callback(message)?, title, text)
+            .await
+    }
+
+    async fn reply_to(
+        &self,
+        target: ReplyTarget<'_>,
+        title: &str,
+        text: &str,
+    ) -> Result<ReplyMessageResponse> {
         let msg_param = MsgParam {
             title: title.to_string(),
             text: text.to_string(),
         };
 
-        if is_private_conversation(data) {
-            let sender_staff_id = data
-                .get("senderStaffId")
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| Error::InvalidConfig {
-                    message: "Missing senderStaffId".to_string(),
-                    source: None,
-                })?;
-
-            let request = OtoMessageRequest {
-                msg_param,
-                msg_key: DEFAULT_MSG_KEY,
-                robot_code: &self.robot_code,
-                user_ids: vec![sender_staff_id],
-            };
-
-            self.send_enterprise_message(&["v1.0", "robot", "oToMessages", "batchSend"], &request)
-                .await
-        } else {
-            let conversation_id = data
-                .get("conversationId")
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| Error::InvalidConfig {
-                    message: "Missing conversationId".to_string(),
-                    source: None,
-                })?;
-
-            let request = GroupMessageRequest {
-                msg_param,
-                msg_key: DEFAULT_MSG_KEY,
-                robot_code: &self.robot_code,
-                open_conversation_id: conversation_id,
-            };
-
-            self.send_enterprise_message(&["v1.0", "robot", "groupMessages", "send"], &request)
+        match target {
+            ReplyTarget::Private(sender_staff_id) => {
+                let request = OtoMessageRequest {
+                    msg_param,
+                    msg_key: DEFAULT_MSG_KEY,
+                    robot_code: &self.robot_code,
+                    user_ids: vec![sender_staff_id],
+                };
+
+                self.send_enterprise_message(
+                    &["v1.0", "robot", "oToMessages", "batchSend"],
+                    &request,
+                )
                 .await
+                .map(ReplyMessageResponse::Private)
+            }
+            ReplyTarget::Group(conversation_id) => {
+                let request = GroupMessageRequest {
+                    msg_param,
+                    msg_key: DEFAULT_MSG_KEY,
+                    robot_code: &self.robot_code,
+                    open_conversation_id: conversation_id,
+                };
+
+                self.send_enterprise_message(&["v1.0", "robot", "groupMessages", "send"], &request)
+                    .await
+                    .map(ReplyMessageResponse::Group)
+            }
         }
     }
 }
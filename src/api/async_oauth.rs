@@ -0,0 +1,88 @@
+use crate::{
+    auth::AppCredentials,
+    client::async_client::Client,
+    error::Result,
+    transport::{build_oauth_authorize_url, parse_json_response},
+    types::{
+        OAuthAuthorizeRequest, OAuthUserProfile, UserAccessToken, internal::UserAccessTokenRequest,
+    },
+};
+
+/// Async user OAuth login ("Log in with DingTalk") service.
+#[derive(Clone)]
+pub struct OAuthService {
+    client: Client,
+    credentials: AppCredentials,
+}
+
+impl OAuthService {
+    pub(crate) fn new(client: Client, credentials: AppCredentials) -> Self {
+        Self {
+            client,
+            credentials,
+        }
+    }
+
+    /// Builds the `login.dingtalk.com/oauth2/auth` URL to redirect the user to.
+    pub fn authorization_url(&self, request: &OAuthAuthorizeRequest) -> Result<String> {
+        Ok(build_oauth_authorize_url(self.credentials.appkey(), request)?.into())
+    }
+
+    /// Exchanges the `authCode` from the login redirect for a user access token.
+    pub async fn get_user_access_token(&self, auth_code: &str) -> Result<UserAccessToken> {
+        self.request_user_access_token(&UserAccessTokenRequest {
+            client_id: self.credentials.appkey(),
+            client_secret: self.credentials.appsecret(),
+            code: Some(auth_code),
+            refresh_token: None,
+            grant_type: "authorization_code",
+        })
+        .await
+    }
+
+    /// Obtains a new user access token with a refresh token.
+    pub async fn refresh_user_access_token(&self, refresh_token: &str) -> Result<UserAccessToken> {
+        self.request_user_access_token(&UserAccessTokenRequest {
+            client_id: self.credentials.appkey(),
+            client_secret: self.credentials.appsecret(),
+            code: None,
+            refresh_token: Some(refresh_token),
+            grant_type: "refresh_token",
+        })
+        .await
+    }
+
+    /// Returns the profile of the user who owns `user_access_token`.
+    pub async fn get_current_user(&self, user_access_token: &str) -> Result<OAuthUserProfile> {
+        let endpoint = self
+            .client
+            .enterprise_endpoint(&["v1.0", "contact", "users", "me"])?;
+        parse_json_response(
+            self.client
+                .enterprise_http()
+                .get(endpoint.as_str())
+                .try_header("x-acs-dingtalk-access-token", user_access_token)?
+                .send_response()
+                .await?,
+            self.client.body_snippet(),
+        )
+    }
+
+    async fn request_user_access_token(
+        &self,
+        request: &UserAccessTokenRequest<'_>,
+    ) -> Result<UserAccessToken> {
+        let endpoint = self
+            .client
+            .enterprise_endpoint(&["v1.0", "oauth2", "userAccessToken"])?;
+        parse_json_response(
+            self.client
+                .enterprise_http()
+                .post(endpoint.as_str())
+                .json(request)?
+                .send_response()
+                .await?,
+            self.client.body_snippet(),
+        )
+    }
+}
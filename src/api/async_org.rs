@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use futures_util::{StreamExt, TryStreamExt, stream};
+
+use crate::{
+    api::EnterpriseService,
+    error::Result,
+    types::{
+        ContactDepartment, ContactGetDepartmentRequest, ContactListSubDepartmentIdsRequest,
+        ContactUser, OrgSnapshot,
+    },
+};
+
+pub(super) const DEFAULT_CONCURRENCY: usize = 4;
+const DEFAULT_PAGE_SIZE: i64 = 100;
+
+/// Async crawler that snapshots a department subtree with bounded concurrency.
+#[derive(Clone)]
+pub struct OrgTreeWalker {
+    service: EnterpriseService,
+    root_dept_id: i64,
+    concurrency: usize,
+    page_size: i64,
+}
+
+impl OrgTreeWalker {
+    /// Creates a walker rooted at `root_dept_id` (`1` is the organization root).
+    #[must_use]
+    pub fn new(service: EnterpriseService, root_dept_id: i64) -> Self {
+        Self {
+            service,
+            root_dept_id,
+            concurrency: DEFAULT_CONCURRENCY,
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// Sets how many departments are fetched at once (minimum `1`).
+    #[must_use]
+    pub fn concurrency(mut self, value: usize) -> Self {
+        self.concurrency = value.max(1);
+        self
+    }
+
+    /// Sets the page size used when listing department users.
+    #[must_use]
+    pub fn page_size(mut self, value: i64) -> Self {
+        self.page_size = value;
+        self
+    }
+
+    /// Crawls the subtree level by level and returns the snapshot.
+    pub async fn walk(&self) -> Result<OrgSnapshot> {
+        let mut snapshot = OrgSnapshot::new(self.root_dept_id);
+        let mut seen = HashSet::from([self.root_dept_id]);
+        let mut frontier = vec![self.root_dept_id];
+
+        while !frontier.is_empty() {
+            let mut visited: Vec<_> = stream::iter(frontier)
+                .map(|dept_id| self.visit(dept_id))
+                .buffer_unordered(self.concurrency)
+                .try_collect()
+                .await?;
+            visited.sort_by_key(|(dept_id, ..)| *dept_id);
+
+            frontier = Vec::new();
+            for (dept_id, detail, sub_dept_ids, users) in visited {
+                frontier.extend(
+                    sub_dept_ids
+                        .iter()
+                        .copied()
+                        .filter(|sub_dept_id| seen.insert(*sub_dept_id)),
+                );
+                snapshot.insert_department(dept_id, detail, sub_dept_ids, users);
+            }
+        }
+
+        Ok(snapshot)
+    }
+
+    async fn visit(
+        &self,
+        dept_id: i64,
+    ) -> Result<(i64, ContactDepartment, Vec<i64>, Vec<ContactUser>)> {
+        let detail = self
+            .service
+            .contact_get_department(ContactGetDepartmentRequest::new(dept_id))
+            .await?;
+        let sub_dept_ids = self
+            .service
+            .contact_list_sub_department_ids(ContactListSubDepartmentIdsRequest::new(dept_id))
+            .await?
+            .dept_id_list;
+        let users = self
+            .service
+            .contact_list_users_stream(dept_id, self.page_size)
+            .try_collect()
+            .await?;
+        Ok((dept_id, detail, sub_dept_ids, users))
+    }
+}
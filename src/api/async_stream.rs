@@ -0,0 +1,313 @@
+use std::future::Future;
+use std::time::Duration;
+
+use futures_util::{Sink, SinkExt, StreamExt, stream::FuturesUnordered};
+use serde_json::Value;
+use tokio_tungstenite::tungstenite::{self, Message};
+use url::Url;
+
+use crate::{
+    auth::AppCredentials,
+    client::async_client::Client,
+    error::{Error, ErrorKind, Result, TransportError},
+    transport::parse_json_response,
+    types::{
+        StreamAck, StreamFrame, StreamFrameType, StreamSubscription,
+        internal::{StreamAckFrame, StreamAckHeaders, StreamOpenRequest, StreamOpenResponse},
+    },
+};
+
+const DEFAULT_USER_AGENT: &str = concat!("dingtalk-sdk-rust/", env!("CARGO_PKG_VERSION"));
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Handler for frames received by a [`StreamClient`].
+///
+/// Every method has a default that acknowledges the frame, so implementors only
+/// override the categories they subscribe to. Frames on one connection may be
+/// handled concurrently.
+pub trait StreamHandler: Send + Sync {
+    /// Handles a `CALLBACK` frame (robot messages, card callbacks).
+    fn on_callback(&self, frame: StreamFrame) -> impl Future<Output = StreamAck> + Send {
+        let _ = frame;
+        async { StreamAck::callback(Value::Null) }
+    }
+
+    /// Handles an `EVENT` frame.
+    fn on_event(&self, frame: StreamFrame) -> impl Future<Output = StreamAck> + Send {
+        let _ = frame;
+        async { StreamAck::success() }
+    }
+
+    /// Observes a `SYSTEM` frame; `ping` and `disconnect` are answered by the client.
+    fn on_system(&self, frame: &StreamFrame) -> impl Future<Output = ()> + Send {
+        let _ = frame;
+        async {}
+    }
+}
+
+/// Async Stream Mode (WebSocket) client for robot messages and events.
+#[derive(Clone)]
+pub struct StreamClient {
+    client: Client,
+    credentials: AppCredentials,
+    subscriptions: Vec<StreamSubscription>,
+    user_agent: String,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl StreamClient {
+    /// Creates a stream client without subscriptions.
+    #[must_use]
+    pub fn new(client: Client, credentials: AppCredentials) -> Self {
+        Self {
+            client,
+            credentials,
+            subscriptions: Vec::new(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Adds a topic subscription.
+    #[must_use]
+    pub fn subscribe(mut self, subscription: StreamSubscription) -> Self {
+        self.subscriptions.push(subscription);
+        self
+    }
+
+    /// Sets the `ua` reported when opening connections.
+    #[must_use]
+    pub fn user_agent(mut self, value: impl Into<String>) -> Self {
+        self.user_agent = value.into();
+        self
+    }
+
+    /// Sets the reconnect backoff; the delay doubles per failure up to `max`.
+    #[must_use]
+    pub fn reconnect_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+        self
+    }
+
+    /// Returns registered subscriptions.
+    #[must_use]
+    pub fn subscriptions(&self) -> &[StreamSubscription] {
+        &self.subscriptions
+    }
+
+    /// Runs the client, reconnecting with backoff after transport failures; the
+    /// backoff restarts once a connection is established.
+    ///
+    /// Returns only when opening a connection fails with a non-transport error
+    /// (for example invalid credentials).
+    pub async fn run<H: StreamHandler>(&self, handler: H) -> Result<()> {
+        let mut backoff = self.initial_backoff;
+        loop {
+            let mut connected = false;
+            match self.serve_connection(&handler, &mut connected).await {
+                Err(error) if !is_reconnectable(&error) => return Err(error),
+                _ => {}
+            }
+            // Only consecutive failures to connect grow the delay.
+            if connected {
+                backoff = self.initial_backoff;
+            }
+            tokio::time::sleep(backoff).await;
+            backoff = backoff.saturating_mul(2).min(self.max_backoff);
+        }
+    }
+
+    /// Opens one connection and dispatches frames until the gateway closes it.
+    pub async fn run_once<H: StreamHandler>(&self, handler: &H) -> Result<()> {
+        self.serve_connection(handler, &mut false).await
+    }
+
+    async fn serve_connection<H: StreamHandler>(
+        &self,
+        handler: &H,
+        connected: &mut bool,
+    ) -> Result<()> {
+        let url = self.open_connection().await?;
+        let (socket, _) = tokio_tungstenite::connect_async(url.as_str())
+            .await
+            .map_err(websocket_error)?;
+        *connected = true;
+        let (mut sink, mut stream) = socket.split();
+        // Handlers run alongside the read loop so a slow handler does not hold
+        // back ping replies or acks for other frames.
+        let mut pending = FuturesUnordered::new();
+        let mut disconnecting = false;
+
+        loop {
+            let message = tokio::select! {
+                message = stream.next() => message,
+                Some(reply) = pending.next() => {
+                    send_reply(&mut sink, reply).await?;
+                    continue;
+                }
+            };
+            let Some(message) = message else {
+                break;
+            };
+            let text = match message.map_err(websocket_error)? {
+                Message::Text(text) => text,
+                Message::Close(_) => break,
+                _ => continue,
+            };
+            let Ok(frame) = serde_json::from_str::<StreamFrame>(text.as_str()) else {
+                if let Some(message_id) = invalid_frame_message_id(text.as_str()) {
+                    let ack = StreamAck::new(400, "Invalid frame", Value::Null);
+                    send_ack(&mut sink, &message_id, &ack).await?;
+                }
+                continue;
+            };
+
+            if frame.frame_type == StreamFrameType::System {
+                match frame.topic() {
+                    Some("ping") => {
+                        let message_id = frame.headers.message_id.clone().unwrap_or_default();
+                        let ack = StreamAck::new(
+                            200,
+                            "OK",
+                            serde_json::from_str(&frame.data).unwrap_or(Value::Null),
+                        );
+                        send_ack(&mut sink, &message_id, &ack).await?;
+                    }
+                    Some("disconnect") => disconnecting = true,
+                    _ => {}
+                }
+            }
+            pending.push(dispatch(handler, frame));
+            if disconnecting {
+                break;
+            }
+        }
+
+        // The gateway keeps the connection open briefly after `disconnect`, so
+        // in-flight handlers can still deliver their acks.
+        if disconnecting {
+            while let Some(reply) = pending.next().await {
+                send_reply(&mut sink, reply).await?;
+            }
+        }
+
+        let _ = sink.close().await;
+        Ok(())
+    }
+
+    async fn open_connection(&self) -> Result<Url> {
+        if self.subscriptions.is_empty() {
+            return Err(Error::InvalidConfig {
+                message: "Stream client requires at least one subscription".to_string(),
+                source: None,
+            });
+        }
+
+        let endpoint =
+            self.client
+                .enterprise_endpoint(&["v1.0", "gateway", "connections", "open"])?;
+        let response: StreamOpenResponse = parse_json_response(
+            self.client
+                .enterprise_http()
+                .post(endpoint.as_str())
+                .json(&StreamOpenRequest {
+                    client_id: self.credentials.appkey(),
+                    client_secret: self.credentials.appsecret(),
+                    subscriptions: &self.subscriptions,
+                    ua: &self.user_agent,
+                })?
+                .send_response()
+                .await?,
+            self.client.body_snippet(),
+        )?;
+
+        let mut url = Url::parse(&response.endpoint).map_err(|source| Error::InvalidConfig {
+            message: format!("Invalid stream endpoint: {}", response.endpoint),
+            source: Some(Box::new(source)),
+        })?;
+        url.query_pairs_mut()
+            .append_pair("ticket", &response.ticket);
+        Ok(url)
+    }
+}
+
+async fn dispatch<H: StreamHandler>(
+    handler: &H,
+    frame: StreamFrame,
+) -> Option<(String, StreamAck)> {
+    let message_id = frame.headers.message_id.clone().unwrap_or_default();
+    let ack = match frame.frame_type {
+        StreamFrameType::System => {
+            handler.on_system(&frame).await;
+            return None;
+        }
+        StreamFrameType::Event => handler.on_event(frame).await,
+        StreamFrameType::Callback => handler.on_callback(frame).await,
+        _ => return None,
+    };
+    Some((message_id, ack))
+}
+
+async fn send_ack<S>(sink: &mut S, message_id: &str, ack: &StreamAck) -> Result<()>
+where
+    S: Sink<Message, Error = tungstenite::Error> + Unpin,
+{
+    let body = serde_json::to_string(&StreamAckFrame {
+        code: ack.code,
+        headers: StreamAckHeaders {
+            content_type: "application/json",
+            message_id,
+        },
+        message: &ack.message,
+        data: &ack.data,
+    })?;
+    sink.send(Message::text(body))
+        .await
+        .map_err(websocket_error)
+}
+
+async fn send_reply<S>(sink: &mut S, reply: Option<(String, StreamAck)>) -> Result<()>
+where
+    S: Sink<Message, Error = tungstenite::Error> + Unpin,
+{
+    match reply {
+        Some((message_id, ack)) => send_ack(sink, &message_id, &ack).await,
+        None => Ok(()),
+    }
+}
+
+fn invalid_frame_message_id(text: &str) -> Option<String> {
+    serde_json::from_str::<Value>(text)
+        .ok()?
+        .pointer("/headers/messageId")?
+        .as_str()
+        .map(str::to_string)
+}
+
+fn is_reconnectable(error: &Error) -> bool {
+    error.is_retryable() || error.kind() == ErrorKind::Transport
+}
+
+fn websocket_error(error: tungstenite::Error) -> Error {
+    let status = match &error {
+        tungstenite::Error::Http(response) => Some(response.status().as_u16()),
+        _ => None,
+    };
+    Error::Transport(Box::new(TransportError {
+        status,
+        message: Some(error.to_string()),
+        request_id: None,
+        body_snippet: None,
+        retry_after: None,
+        retryable: true,
+        code: "websocket",
+        method: None,
+        uri: None,
+        timeout_phase: None,
+        transport_kind: None,
+    }))
+}
@@ -0,0 +1,58 @@
+use crate::{
+    client::blocking_client::BlockingClient,
+    error::Result,
+    transport::{build_session_webhook_url, parse_standard_api_response},
+    types::{WebhookMessage, WebhookSendResponse},
+};
+
+/// Blocking sender for a robot callback `sessionWebhook`.
+///
+/// Session webhooks let a robot reply to the conversation of an incoming
+/// callback without an access token until `sessionWebhookExpiredTime`.
+#[derive(Clone)]
+pub struct BlockingSessionWebhook {
+    client: BlockingClient,
+    url: String,
+    expired_at_millis: Option<i64>,
+}
+
+impl BlockingSessionWebhook {
+    pub(crate) fn new(
+        client: BlockingClient,
+        url: impl Into<String>,
+        expired_at_millis: Option<i64>,
+    ) -> Self {
+        Self {
+            client,
+            url: url.into(),
+            expired_at_millis,
+        }
+    }
+
+    /// Returns the session webhook URL.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the session expiry as a millisecond Unix timestamp, when known.
+    #[must_use]
+    pub fn expired_at_millis(&self) -> Option<i64> {
+        self.expired_at_millis
+    }
+
+    /// Sends a webhook message to the session.
+    ///
+    /// Returns [`Error::SessionExpired`](crate::Error::SessionExpired) without
+    /// sending when the session has already expired.
+    pub fn send(&self, message: &WebhookMessage) -> Result<WebhookSendResponse> {
+        let url = build_session_webhook_url(&self.url, self.expired_at_millis)?;
+        let response = self
+            .client
+            .webhook_http()
+            .post(url.as_str())
+            .json(message)?
+            .send_response()?;
+        parse_standard_api_response(response, self.client.body_snippet())
+    }
+}
@@ -1,13 +1,10 @@
 use crate::{
     client::blocking_client::BlockingClient,
     error::Result,
-    transport::{build_webhook_url, parse_standard_api_text_response},
+    transport::{build_webhook_url, parse_standard_api_response},
     types::{
-        ActionCardButton, FeedCardLink,
-        internal::{
-            ActionCardContent, FeedCardContent, LinkContent, MarkdownContent, TextContent,
-            WebhookMessage, build_at,
-        },
+        ActionCardButton, ActionCardMessage, FeedCardLink, FeedCardMessage, LinkMessage,
+        MarkdownMessage, TextMessage, WebhookAt, WebhookMessage, WebhookSendResponse,
     },
 };
 
@@ -32,7 +29,8 @@ impl BlockingWebhookService {
         }
     }
 
-    fn send_message(&self, message: &WebhookMessage) -> Result<String> {
+    /// Sends a webhook message.
+    pub fn send(&self, message: &WebhookMessage) -> Result<WebhookSendResponse> {
         let url = build_webhook_url(
             self.client.webhook_base_url(),
             &self.token,
@@ -44,7 +42,7 @@ impl BlockingWebhookService {
             .post(url.as_str())
             .json(message)?
             .send_response()?;
-        parse_standard_api_text_response(response, self.client.body_snippet())
+        parse_standard_api_response(response, self.client.body_snippet())
     }
 
     /// Sends a text webhook message.
@@ -54,14 +52,12 @@ impl BlockingWebhookService {
         at_mobiles: Option<Vec<String>>,
         at_user_ids: Option<Vec<String>>,
         is_at_all: Option<bool>,
-    ) -> Result<String> {
-        let message = WebhookMessage::Text {
-            text: TextContent {
-                content: content.to_string(),
-            },
-            at: build_at(at_mobiles, at_user_ids, is_at_all),
+    ) -> Result<WebhookSendResponse> {
+        let message = TextMessage {
+            content: content.to_string(),
+            at: WebhookAt::from_parts(at_mobiles, at_user_ids, is_at_all),
         };
-        self.send_message(&message)
+        self.send(&message.into())
     }
 
     /// Sends a link webhook message.
@@ -71,17 +67,14 @@ impl BlockingWebhookService {
         text: &str,
         message_url: &str,
         pic_url: Option<&str>,
-    ) -> Result<String> {
-        let message = WebhookMessage::Link {
-            link: LinkContent {
-                title: title.to_string(),
-                text: text.to_string(),
-                message_url: message_url.to_string(),
-                pic_url: pic_url.map(ToOwned::to_owned),
-            },
-            at: None,
+    ) -> Result<WebhookSendResponse> {
+        let message = LinkMessage {
+            title: title.to_string(),
+            text: text.to_string(),
+            message_url: message_url.to_string(),
+            pic_url: pic_url.map(ToOwned::to_owned),
         };
-        self.send_message(&message)
+        self.send(&message.into())
     }
 
     /// Sends a markdown webhook message.
@@ -92,15 +85,13 @@ impl BlockingWebhookService {
         at_mobiles: Option<Vec<String>>,
         at_user_ids: Option<Vec<String>>,
         is_at_all: Option<bool>,
-    ) -> Result<String> {
-        let message = WebhookMessage::Markdown {
-            markdown: MarkdownContent {
-                title: title.to_string(),
-                text: text.to_string(),
-            },
-            at: build_at(at_mobiles, at_user_ids, is_at_all),
+    ) -> Result<WebhookSendResponse> {
+        let message = MarkdownMessage {
+            title: title.to_string(),
+            text: text.to_string(),
+            at: WebhookAt::from_parts(at_mobiles, at_user_ids, is_at_all),
         };
-        self.send_message(&message)
+        self.send(&message.into())
     }
 
     /// Sends a single-button action-card webhook message.
@@ -111,18 +102,10 @@ impl BlockingWebhookService {
         single_title: &str,
         single_url: &str,
         btn_orientation: Option<&str>,
-    ) -> Result<String> {
-        let message = WebhookMessage::ActionCard {
-            action_card: ActionCardContent {
-                title: title.to_string(),
-                text: text.to_string(),
-                btn_orientation: btn_orientation.map(ToOwned::to_owned),
-                single_title: Some(single_title.to_string()),
-                single_url: Some(single_url.to_string()),
-                btns: None,
-            },
-        };
-        self.send_message(&message)
+    ) -> Result<WebhookSendResponse> {
+        let mut message = ActionCardMessage::single(title, text, single_title, single_url);
+        message.btn_orientation = btn_orientation.map(ToOwned::to_owned);
+        self.send(&message.into())
     }
 
     /// Sends a multi-button action-card webhook message.
@@ -132,25 +115,14 @@ impl BlockingWebhookService {
         text: &str,
         btns: Vec<ActionCardButton>,
         btn_orientation: Option<&str>,
-    ) -> Result<String> {
-        let message = WebhookMessage::ActionCard {
-            action_card: ActionCardContent {
-                title: title.to_string(),
-                text: text.to_string(),
-                btn_orientation: btn_orientation.map(ToOwned::to_owned),
-                single_title: None,
-                single_url: None,
-                btns: Some(btns),
-            },
-        };
-        self.send_message(&message)
+    ) -> Result<WebhookSendResponse> {
+        let mut message = ActionCardMessage::multi(title, text, btns);
+        message.btn_orientation = btn_orientation.map(ToOwned::to_owned);
+        self.send(&message.into())
     }
 
     /// Sends a feed-card webhook message.
-    pub fn send_feed_card_message(&self, links: Vec<FeedCardLink>) -> Result<String> {
-        let message = WebhookMessage::FeedCard {
-            feed_card: FeedCardContent { links },
-        };
-        self.send_message(&message)
+    pub fn send_feed_card_message(&self, links: Vec<FeedCardLink>) -> Result<WebhookSendResponse> {
+        self.send(&FeedCardMessage::new(links).into())
     }
 }
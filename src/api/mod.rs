@@ -1,30 +1,126 @@
 #[cfg(feature = "_async")]
 mod async_enterprise;
 #[cfg(feature = "_async")]
+mod async_oauth;
+#[cfg(feature = "_async")]
+mod async_org;
+#[cfg(feature = "_async")]
+mod async_session_webhook;
+#[cfg(feature = "stream")]
+mod async_stream;
+#[cfg(feature = "_async")]
 mod async_webhook;
 #[cfg(feature = "_blocking")]
 mod blocking_enterprise;
 #[cfg(feature = "_blocking")]
+mod blocking_oauth;
+#[cfg(feature = "_blocking")]
+mod blocking_org;
+#[cfg(feature = "_blocking")]
+mod blocking_session_webhook;
+#[cfg(feature = "_blocking")]
 mod blocking_webhook;
+mod pagination;
 
 #[cfg(feature = "_async")]
 /// Async enterprise service.
 pub use async_enterprise::EnterpriseService;
 #[cfg(feature = "_async")]
+/// Async user OAuth login service.
+pub use async_oauth::OAuthService;
+#[cfg(feature = "_async")]
+/// Async organization tree walker.
+pub use async_org::OrgTreeWalker;
+#[cfg(feature = "_async")]
+/// Async session webhook sender.
+pub use async_session_webhook::SessionWebhook;
+#[cfg(feature = "stream")]
+/// Async Stream Mode client.
+pub use async_stream::{StreamClient, StreamHandler};
+#[cfg(feature = "_async")]
 /// Async webhook service.
 pub use async_webhook::WebhookService;
 #[cfg(feature = "_blocking")]
 /// Blocking enterprise service.
 pub use blocking_enterprise::BlockingEnterpriseService;
 #[cfg(feature = "_blocking")]
+/// Blocking user OAuth login service.
+pub use blocking_oauth::BlockingOAuthService;
+#[cfg(feature = "_blocking")]
+/// Blocking organization tree walker.
+pub use blocking_org::BlockingOrgTreeWalker;
+#[cfg(feature = "_blocking")]
+/// Blocking session webhook sender.
+pub use blocking_session_webhook::BlockingSessionWebhook;
+#[cfg(feature = "_blocking")]
 /// Blocking webhook service.
 pub use blocking_webhook::BlockingWebhookService;
 
+use crate::{
+    error::{Error, Result},
+    types::RobotCallbackMessage,
+};
+
 pub(crate) fn is_private_conversation(data: &serde_json::Value) -> bool {
     data.get("conversationType")
         .is_some_and(|value| value.as_str() == Some("1") || value.as_i64() == Some(1))
 }
 
+/// Destination of a reply to an incoming robot message.
+pub(crate) enum ReplyTarget<'a> {
+    /// One-to-one reply to the sender's staff id.
+    Private(&'a str),
+    /// Group reply to the open conversation id.
+    Group(&'a str),
+}
+
+impl<'a> ReplyTarget<'a> {
+    pub(crate) fn from_value(data: &'a serde_json::Value) -> Result<Self> {
+        if is_private_conversation(data) {
+            data.get("senderStaffId")
+                .and_then(|v| v.as_str())
+                .map(Self::Private)
+                .ok_or_else(|| missing_field("senderStaffId"))
+        } else {
+            data.get("conversationId")
+                .and_then(|v| v.as_str())
+                .map(Self::Group)
+                .ok_or_else(|| missing_field("conversationId"))
+        }
+    }
+
+    pub(crate) fn from_callback(message: &'a RobotCallbackMessage) -> Result<Self> {
+        if message.is_private() {
+            message
+                .sender_staff_id
+                .as_deref()
+                .map(Self::Private)
+                .ok_or_else(|| missing_field("senderStaffId"))
+        } else {
+            message
+                .conversation_id
+                .as_deref()
+                .map(Self::Group)
+                .ok_or_else(|| missing_field("conversationId"))
+        }
+    }
+}
+
+pub(crate) fn session_webhook_parts(message: &RobotCallbackMessage) -> Result<(&str, Option<i64>)> {
+    let url = message
+        .session_webhook
+        .as_deref()
+        .ok_or_else(|| missing_field("sessionWebhook"))?;
+    Ok((url, message.session_webhook_expired_time))
+}
+
+fn missing_field(name: &str) -> Error {
+    Error::InvalidConfig {
+        message: format!("Missing {name}"),
+        source: None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::is_private_conversation;
@@ -0,0 +1,270 @@
+use crate::{
+    error::Result,
+    types::{
+        ApprovalListProcessInstanceIdsRequest, ApprovalListProcessInstanceIdsResult,
+        ContactEmpLeaveRecord, ContactEmpLeaveRecordsResult, ContactListEmpLeaveRecordsRequest,
+        ContactListInactiveUsersRequest, ContactListInactiveUsersResult,
+        ContactListSimpleUsersRequest, ContactListSimpleUsersResult, ContactListUsersRequest,
+        ContactListUsersResult, ContactSimpleUser, ContactUser, RoleGroup, RoleListRequest,
+        RoleListResult, RoleListUsersRequest, RoleListUsersResult, RoleUser,
+    },
+};
+
+/// Request of a cursor-paginated list API.
+pub(crate) trait CursorRequest: Clone {
+    type Cursor: PartialEq;
+
+    fn cursor(&self) -> Self::Cursor;
+    fn set_cursor(&mut self, cursor: Self::Cursor);
+}
+
+/// Page returned by a cursor-paginated list API.
+pub(crate) trait CursorPage {
+    type Item;
+    type Cursor;
+
+    /// Splits the page into its items and the cursor of the next page, if any.
+    ///
+    /// `cursor` is the cursor (or offset) of the request that returned this page.
+    fn into_parts(self, cursor: Self::Cursor) -> (Vec<Self::Item>, Option<Self::Cursor>);
+}
+
+impl CursorRequest for ContactListUsersRequest {
+    type Cursor = i64;
+
+    fn cursor(&self) -> i64 {
+        self.cursor
+    }
+
+    fn set_cursor(&mut self, cursor: i64) {
+        self.cursor = cursor;
+    }
+}
+
+impl CursorPage for ContactListUsersResult {
+    type Item = ContactUser;
+    type Cursor = i64;
+
+    fn into_parts(self, _cursor: i64) -> (Vec<ContactUser>, Option<i64>) {
+        let has_more = self.has_more.unwrap_or(self.next_cursor.is_some());
+        (self.list, self.next_cursor.filter(|_| has_more))
+    }
+}
+
+impl CursorRequest for ContactListSimpleUsersRequest {
+    type Cursor = i64;
+
+    fn cursor(&self) -> i64 {
+        self.cursor
+    }
+
+    fn set_cursor(&mut self, cursor: i64) {
+        self.cursor = cursor;
+    }
+}
+
+impl CursorPage for ContactListSimpleUsersResult {
+    type Item = ContactSimpleUser;
+    type Cursor = i64;
+
+    fn into_parts(self, _cursor: i64) -> (Vec<ContactSimpleUser>, Option<i64>) {
+        let has_more = self.has_more.unwrap_or(self.next_cursor.is_some());
+        (self.list, self.next_cursor.filter(|_| has_more))
+    }
+}
+
+impl CursorRequest for ContactListInactiveUsersRequest {
+    type Cursor = i64;
+
+    fn cursor(&self) -> i64 {
+        self.offset
+    }
+
+    fn set_cursor(&mut self, cursor: i64) {
+        self.offset = cursor;
+    }
+}
+
+impl CursorPage for ContactListInactiveUsersResult {
+    type Item = String;
+    type Cursor = i64;
+
+    fn into_parts(self, cursor: i64) -> (Vec<String>, Option<i64>) {
+        let next_offset = self.next_cursor.unwrap_or(cursor + self.list.len() as i64);
+        let has_more = self.has_more.unwrap_or(self.next_cursor.is_some());
+        (self.list, has_more.then_some(next_offset))
+    }
+}
+
+/// The first page is requested without a token, i.e. with an empty cursor.
+impl CursorRequest for ContactListEmpLeaveRecordsRequest {
+    type Cursor = String;
+
+    fn cursor(&self) -> String {
+        self.next_token.clone().unwrap_or_default()
+    }
+
+    fn set_cursor(&mut self, cursor: String) {
+        self.next_token = Some(cursor);
+    }
+}
+
+impl CursorPage for ContactEmpLeaveRecordsResult {
+    type Item = ContactEmpLeaveRecord;
+    type Cursor = String;
+
+    fn into_parts(self, _cursor: String) -> (Vec<ContactEmpLeaveRecord>, Option<String>) {
+        let next_token = self.next_token.filter(|token| !token.is_empty());
+        (self.records, next_token)
+    }
+}
+
+impl CursorRequest for RoleListRequest {
+    type Cursor = i64;
+
+    fn cursor(&self) -> i64 {
+        self.offset
+    }
+
+    fn set_cursor(&mut self, cursor: i64) {
+        self.offset = cursor;
+    }
+}
+
+impl CursorPage for RoleListResult {
+    type Item = RoleGroup;
+    type Cursor = i64;
+
+    fn into_parts(self, cursor: i64) -> (Vec<RoleGroup>, Option<i64>) {
+        let next_offset = cursor + self.list.len() as i64;
+        let has_more = self.has_more.unwrap_or(false) && !self.list.is_empty();
+        (self.list, has_more.then_some(next_offset))
+    }
+}
+
+impl CursorRequest for RoleListUsersRequest {
+    type Cursor = i64;
+
+    fn cursor(&self) -> i64 {
+        self.offset
+    }
+
+    fn set_cursor(&mut self, cursor: i64) {
+        self.offset = cursor;
+    }
+}
+
+impl CursorPage for RoleListUsersResult {
+    type Item = RoleUser;
+    type Cursor = i64;
+
+    fn into_parts(self, cursor: i64) -> (Vec<RoleUser>, Option<i64>) {
+        let next_offset = self.next_cursor.unwrap_or(cursor + self.list.len() as i64);
+        let has_more = self.has_more.unwrap_or(self.next_cursor.is_some());
+        (self.list, has_more.then_some(next_offset))
+    }
+}
+
+impl CursorRequest for ApprovalListProcessInstanceIdsRequest {
+    type Cursor = i64;
+
+    fn cursor(&self) -> i64 {
+        self.cursor
+    }
+
+    fn set_cursor(&mut self, cursor: i64) {
+        self.cursor = cursor;
+    }
+}
+
+impl CursorPage for ApprovalListProcessInstanceIdsResult {
+    type Item = String;
+    type Cursor = i64;
+
+    fn into_parts(self, _cursor: i64) -> (Vec<String>, Option<i64>) {
+        (self.list, self.next_cursor)
+    }
+}
+
+/// Buffered items of the current page plus the request for the next one.
+struct CursorState<Q, T> {
+    next_request: Option<Q>,
+    items: std::vec::IntoIter<T>,
+}
+
+impl<Q: CursorRequest, T> CursorState<Q, T> {
+    fn new(request: Q) -> Self {
+        Self {
+            next_request: Some(request),
+            items: Vec::new().into_iter(),
+        }
+    }
+
+    fn advance<P: CursorPage<Item = T, Cursor = Q::Cursor>>(&mut self, request: Q, page: P) {
+        let (items, next_cursor) = page.into_parts(request.cursor());
+        self.items = items.into_iter();
+        // A cursor that does not move would page forever.
+        self.next_request = next_cursor
+            .filter(|cursor| *cursor != request.cursor())
+            .map(|cursor| {
+                let mut request = request;
+                request.set_cursor(cursor);
+                request
+            });
+    }
+}
+
+/// Streams every item of a cursor-paginated API, stopping after the first error.
+#[cfg(feature = "_async")]
+pub(crate) fn cursor_stream<'a, Q, P, F, Fut>(
+    request: Q,
+    fetch: F,
+) -> impl futures_util::Stream<Item = Result<P::Item>> + 'a
+where
+    Q: CursorRequest + 'a,
+    P: CursorPage<Cursor = Q::Cursor> + 'a,
+    F: Fn(Q) -> Fut + 'a,
+    Fut: std::future::Future<Output = Result<P>> + 'a,
+{
+    futures_util::stream::unfold(
+        (CursorState::new(request), fetch),
+        |(mut state, fetch)| async move {
+            loop {
+                if let Some(item) = state.items.next() {
+                    return Some((Ok(item), (state, fetch)));
+                }
+                let request = state.next_request.take()?;
+                match fetch(request.clone()).await {
+                    Ok(page) => state.advance(request, page),
+                    Err(error) => return Some((Err(error), (state, fetch))),
+                }
+            }
+        },
+    )
+}
+
+/// Iterates every item of a cursor-paginated API, stopping after the first error.
+#[cfg(feature = "_blocking")]
+pub(crate) fn cursor_iter<'a, Q, P, F>(
+    request: Q,
+    fetch: F,
+) -> impl Iterator<Item = Result<P::Item>> + 'a
+where
+    Q: CursorRequest + 'a,
+    P: CursorPage<Cursor = Q::Cursor> + 'a,
+    F: Fn(Q) -> Result<P> + 'a,
+{
+    let mut state = CursorState::new(request);
+    std::iter::from_fn(move || {
+        loop {
+            if let Some(item) = state.items.next() {
+                return Some(Ok(item));
+            }
+            let request = state.next_request.take()?;
+            match fetch(request.clone()) {
+                Ok(page) => state.advance(request, page),
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    })
+}
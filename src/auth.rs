@@ -1,4 +1,9 @@
-use std::fmt;
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, RwLock},
+    time::SystemTime,
+};
 
 /// Enterprise app credentials (`appkey` + `appsecret`).
 ///
@@ -40,3 +45,209 @@ impl fmt::Debug for AppCredentials {
             .finish()
     }
 }
+
+/// Source of the latest `suite_ticket` pushed to a third-party (ISV) suite.
+///
+/// DingTalk pushes a fresh ticket to the suite callback roughly every 20
+/// minutes; the provider returns the most recent one. Closures of type
+/// `Fn(&str) -> Option<String>` implement this trait.
+pub trait SuiteTicketProvider: Send + Sync {
+    /// Returns the current ticket for `suite_key`.
+    fn suite_ticket(&self, suite_key: &str) -> Option<String>;
+}
+
+impl<F> SuiteTicketProvider for F
+where
+    F: Fn(&str) -> Option<String> + Send + Sync,
+{
+    fn suite_ticket(&self, suite_key: &str) -> Option<String> {
+        self(suite_key)
+    }
+}
+
+/// In-process [`SuiteTicketProvider`] updated from suite ticket callbacks.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySuiteTicketStore {
+    inner: Arc<RwLock<HashMap<String, String>>>,
+}
+
+impl InMemorySuiteTicketStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the latest ticket pushed for `suite_key`.
+    pub fn set(&self, suite_key: impl Into<String>, suite_ticket: impl Into<String>) {
+        if let Ok(mut guard) = self.inner.write() {
+            guard.insert(suite_key.into(), suite_ticket.into());
+        }
+    }
+}
+
+impl SuiteTicketProvider for InMemorySuiteTicketStore {
+    fn suite_ticket(&self, suite_key: &str) -> Option<String> {
+        self.inner.read().ok()?.get(suite_key).cloned()
+    }
+}
+
+/// Third-party (ISV) suite credentials (`suiteKey` + `suiteSecret`) with the
+/// provider of the current suite ticket.
+///
+/// `Debug` output redacts `suite_secret`.
+#[derive(Clone)]
+pub struct SuiteCredentials {
+    suite_key: String,
+    suite_secret: String,
+    ticket_provider: Arc<dyn SuiteTicketProvider>,
+}
+
+impl SuiteCredentials {
+    /// Creates suite credentials from suite key, suite secret, and ticket provider.
+    #[must_use]
+    pub fn new(
+        suite_key: impl Into<String>,
+        suite_secret: impl Into<String>,
+        ticket_provider: impl SuiteTicketProvider + 'static,
+    ) -> Self {
+        Self {
+            suite_key: suite_key.into(),
+            suite_secret: suite_secret.into(),
+            ticket_provider: Arc::new(ticket_provider),
+        }
+    }
+
+    /// Returns the suite key.
+    #[must_use]
+    pub fn suite_key(&self) -> &str {
+        &self.suite_key
+    }
+
+    /// Returns the suite secret.
+    #[must_use]
+    pub fn suite_secret(&self) -> &str {
+        &self.suite_secret
+    }
+
+    /// Returns the current suite ticket from the provider.
+    #[must_use]
+    pub fn suite_ticket(&self) -> Option<String> {
+        self.ticket_provider.suite_ticket(&self.suite_key)
+    }
+}
+
+impl fmt::Debug for SuiteCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SuiteCredentials")
+            .field("suite_key", &self.suite_key)
+            .field("suite_secret", &"<redacted>")
+            .finish_non_exhaustive()
+    }
+}
+
+/// Credentials an enterprise service exchanges for access tokens.
+#[derive(Debug, Clone)]
+pub(crate) enum TokenCredentials {
+    App(AppCredentials),
+    Corp {
+        suite: SuiteCredentials,
+        corp_id: String,
+    },
+}
+
+impl TokenCredentials {
+    /// Returns the key used for the token cache and refresh locks.
+    pub(crate) fn cache_key(&self) -> String {
+        match self {
+            Self::App(credentials) => credentials.appkey().to_string(),
+            Self::Corp { suite, corp_id } => format!("{}:{corp_id}", suite.suite_key()),
+        }
+    }
+}
+
+/// Access token together with its absolute expiry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CachedAccessToken {
+    /// Access token value.
+    pub token: String,
+    /// Wall-clock expiry, comparable across processes.
+    pub expires_at: SystemTime,
+}
+
+impl CachedAccessToken {
+    /// Creates a cached token entry.
+    #[must_use]
+    pub fn new(token: impl Into<String>, expires_at: SystemTime) -> Self {
+        Self {
+            token: token.into(),
+            expires_at,
+        }
+    }
+}
+
+/// Storage backend for enterprise access tokens.
+///
+/// Entries are keyed by app key, or by `"{suite_key}:{corp_id}"` for corp
+/// tokens obtained with [`SuiteCredentials`].
+///
+/// Implement this to share tokens between processes (for example in Redis or a
+/// file) and register it with `ClientBuilder::access_token_store`. The client
+/// applies `token_refresh_margin` itself, so stores may return expired entries.
+pub trait AccessTokenStore: Send + Sync {
+    /// Returns the stored token for `key`.
+    fn get(&self, key: &str) -> Option<CachedAccessToken>;
+
+    /// Stores or replaces the token for `key`.
+    fn store(&self, key: &str, token: CachedAccessToken);
+
+    /// Removes the token for `key`.
+    fn invalidate(&self, key: &str);
+}
+
+impl<T: AccessTokenStore + ?Sized> AccessTokenStore for Arc<T> {
+    fn get(&self, key: &str) -> Option<CachedAccessToken> {
+        (**self).get(key)
+    }
+
+    fn store(&self, key: &str, token: CachedAccessToken) {
+        (**self).store(key, token);
+    }
+
+    fn invalidate(&self, key: &str) {
+        (**self).invalidate(key);
+    }
+}
+
+/// In-process [`AccessTokenStore`], used by default.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAccessTokenStore {
+    inner: Arc<RwLock<HashMap<String, CachedAccessToken>>>,
+}
+
+impl InMemoryAccessTokenStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AccessTokenStore for InMemoryAccessTokenStore {
+    fn get(&self, key: &str) -> Option<CachedAccessToken> {
+        self.inner.read().ok()?.get(key).cloned()
+    }
+
+    fn store(&self, key: &str, token: CachedAccessToken) {
+        if let Ok(mut guard) = self.inner.write() {
+            guard.insert(key.to_string(), token);
+        }
+    }
+
+    fn invalidate(&self, key: &str) {
+        if let Ok(mut guard) = self.inner.write() {
+            guard.remove(key);
+        }
+    }
+}
@@ -3,11 +3,15 @@ use std::sync::Arc;
 use reqx::{advanced::PermissiveRetryEligibility, prelude::Client as HttpClient};
 use url::Url;
 
+#[cfg(feature = "stream")]
+use crate::api::StreamClient;
 use crate::{
-    api::{EnterpriseService, WebhookService},
-    auth::AppCredentials,
+    api::{self, EnterpriseService, OAuthService, SessionWebhook, WebhookService},
+    auth::{AppCredentials, SuiteCredentials, TokenCredentials},
     client::shared::{self, BuilderConfig, SharedClientState},
     error::{Error, Result},
+    transport::{RefreshSlot, TokenRefreshes},
+    types::RobotCallbackMessage,
 };
 
 /// Builder for async [`Client`].
@@ -29,12 +33,8 @@ impl ClientBuilder {
             inner: Arc::new(Inner {
                 webhook_http,
                 enterprise_http,
-                shared: SharedClientState::new(
-                    base_urls,
-                    self.config.cache_access_token,
-                    self.config.token_refresh_margin,
-                    self.config.body_snippet,
-                ),
+                shared: SharedClientState::new(base_urls, &self.config),
+                token_refreshes: TokenRefreshes::default(),
             }),
         })
     }
@@ -83,6 +83,7 @@ struct Inner {
     webhook_http: HttpClient,
     enterprise_http: HttpClient,
     shared: SharedClientState,
+    token_refreshes: TokenRefreshes<tokio::sync::OnceCell<Result<String>>>,
 }
 
 impl Client {
@@ -111,7 +112,69 @@ impl Client {
         appsecret: impl Into<String>,
         robot_code: impl Into<String>,
     ) -> EnterpriseService {
-        EnterpriseService::new(self.clone(), appkey, appsecret, robot_code)
+        EnterpriseService::new(
+            self.clone(),
+            TokenCredentials::App(AppCredentials::new(appkey, appsecret)),
+            robot_code,
+        )
+    }
+
+    /// Creates an enterprise robot service for a corp that authorized a
+    /// third-party (ISV) suite.
+    ///
+    /// Access tokens come from `/v1.0/oauth2/corpAccessToken` and are cached per
+    /// suite and `corp_id`.
+    #[must_use]
+    pub fn corp_enterprise(
+        &self,
+        suite: SuiteCredentials,
+        corp_id: impl Into<String>,
+        robot_code: impl Into<String>,
+    ) -> EnterpriseService {
+        EnterpriseService::new(
+            self.clone(),
+            TokenCredentials::Corp {
+                suite,
+                corp_id: corp_id.into(),
+            },
+            robot_code,
+        )
+    }
+
+    /// Creates a Stream Mode client for the app credentials.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    #[must_use]
+    pub fn stream(&self, credentials: AppCredentials) -> StreamClient {
+        StreamClient::new(self.clone(), credentials)
+    }
+
+    /// Creates a user OAuth login service for the app credentials.
+    #[must_use]
+    pub fn oauth(&self, credentials: AppCredentials) -> OAuthService {
+        OAuthService::new(self.clone(), credentials)
+    }
+
+    /// Creates a sender for a robot callback `sessionWebhook`.
+    ///
+    /// `expired_at_millis` is `sessionWebhookExpiredTime`; sends fail with
+    /// [`ErrorKind::SessionExpired`](crate::ErrorKind::SessionExpired) once it has passed.
+    #[must_use]
+    pub fn session_webhook(
+        &self,
+        url: impl Into<String>,
+        expired_at_millis: Option<i64>,
+    ) -> SessionWebhook {
+        SessionWebhook::new(self.clone(), url, expired_at_millis)
+    }
+
+    /// Creates a sender for the `sessionWebhook` of an incoming callback.
+    pub fn session_webhook_from_callback(
+        &self,
+        message: &RobotCallbackMessage,
+    ) -> Result<SessionWebhook> {
+        let (url, expired_at_millis) = api::session_webhook_parts(message)?;
+        Ok(self.session_webhook(url, expired_at_millis))
     }
 
     pub(crate) fn webhook_http(&self) -> &HttpClient {
@@ -134,19 +197,40 @@ impl Client {
         self.inner.shared.enterprise_endpoint(segments)
     }
 
-    pub(crate) fn cached_access_token(&self, credentials: &AppCredentials) -> Option<String> {
-        self.inner.shared.cached_access_token(credentials)
+    pub(crate) fn cached_access_token(&self, cache_key: &str) -> Option<String> {
+        self.inner.shared.cached_access_token(cache_key)
     }
 
     pub(crate) fn store_access_token(
         &self,
-        credentials: &AppCredentials,
+        cache_key: &str,
         token: String,
         expires_in_seconds: Option<i64>,
     ) {
         self.inner
             .shared
-            .store_access_token(credentials, token, expires_in_seconds);
+            .store_access_token(cache_key, token, expires_in_seconds);
+    }
+
+    pub(crate) fn invalidate_access_token(&self, cache_key: &str, stale_token: &str) {
+        self.inner
+            .shared
+            .invalidate_access_token(cache_key, stale_token);
+    }
+
+    pub(crate) fn retry_on_invalid_token(&self) -> bool {
+        self.inner.shared.retry_on_invalid_token()
+    }
+
+    pub(crate) fn join_token_refresh(
+        &self,
+        cache_key: &str,
+    ) -> RefreshSlot<'_, tokio::sync::OnceCell<Result<String>>> {
+        self.inner.token_refreshes.join(cache_key)
+    }
+
+    pub(crate) fn token_endpoint(&self) -> crate::transport::TokenEndpoint {
+        self.inner.shared.token_endpoint()
     }
 
     pub(crate) fn body_snippet(&self) -> crate::transport::BodySnippetConfig {
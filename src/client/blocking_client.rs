@@ -4,10 +4,15 @@ use reqx::{advanced::PermissiveRetryEligibility, blocking::Client as HttpClient}
 use url::Url;
 
 use crate::{
-    api::{BlockingEnterpriseService, BlockingWebhookService},
-    auth::AppCredentials,
+    api::{
+        self, BlockingEnterpriseService, BlockingOAuthService, BlockingSessionWebhook,
+        BlockingWebhookService,
+    },
+    auth::{AppCredentials, SuiteCredentials, TokenCredentials},
     client::shared::{self, BuilderConfig, SharedClientState},
     error::{Error, Result},
+    transport::{RefreshSlot, TokenRefreshes},
+    types::RobotCallbackMessage,
 };
 
 /// Builder for blocking [`BlockingClient`].
@@ -29,12 +34,8 @@ impl BlockingClientBuilder {
             inner: Arc::new(Inner {
                 webhook_http,
                 enterprise_http,
-                shared: SharedClientState::new(
-                    base_urls,
-                    self.config.cache_access_token,
-                    self.config.token_refresh_margin,
-                    self.config.body_snippet,
-                ),
+                shared: SharedClientState::new(base_urls, &self.config),
+                token_refreshes: TokenRefreshes::default(),
             }),
         })
     }
@@ -83,6 +84,7 @@ struct Inner {
     webhook_http: HttpClient,
     enterprise_http: HttpClient,
     shared: SharedClientState,
+    token_refreshes: TokenRefreshes<std::sync::OnceLock<Result<String>>>,
 }
 
 impl BlockingClient {
@@ -115,7 +117,61 @@ impl BlockingClient {
         appsecret: impl Into<String>,
         robot_code: impl Into<String>,
     ) -> BlockingEnterpriseService {
-        BlockingEnterpriseService::new(self.clone(), appkey, appsecret, robot_code)
+        BlockingEnterpriseService::new(
+            self.clone(),
+            TokenCredentials::App(AppCredentials::new(appkey, appsecret)),
+            robot_code,
+        )
+    }
+
+    /// Creates a blocking enterprise robot service for a corp that authorized a
+    /// third-party (ISV) suite.
+    ///
+    /// Access tokens come from `/v1.0/oauth2/corpAccessToken` and are cached per
+    /// suite and `corp_id`.
+    #[must_use]
+    pub fn corp_enterprise(
+        &self,
+        suite: SuiteCredentials,
+        corp_id: impl Into<String>,
+        robot_code: impl Into<String>,
+    ) -> BlockingEnterpriseService {
+        BlockingEnterpriseService::new(
+            self.clone(),
+            TokenCredentials::Corp {
+                suite,
+                corp_id: corp_id.into(),
+            },
+            robot_code,
+        )
+    }
+
+    /// Creates a blocking user OAuth login service for the app credentials.
+    #[must_use]
+    pub fn oauth(&self, credentials: AppCredentials) -> BlockingOAuthService {
+        BlockingOAuthService::new(self.clone(), credentials)
+    }
+
+    /// Creates a blocking sender for a robot callback `sessionWebhook`.
+    ///
+    /// `expired_at_millis` is `sessionWebhookExpiredTime`; sends fail with
+    /// [`ErrorKind::SessionExpired`](crate::ErrorKind::SessionExpired) once it has passed.
+    #[must_use]
+    pub fn session_webhook(
+        &self,
+        url: impl Into<String>,
+        expired_at_millis: Option<i64>,
+    ) -> BlockingSessionWebhook {
+        BlockingSessionWebhook::new(self.clone(), url, expired_at_millis)
+    }
+
+    /// Creates a blocking sender for the `sessionWebhook` of an incoming callback.
+    pub fn session_webhook_from_callback(
+        &self,
+        message: &RobotCallbackMessage,
+    ) -> Result<BlockingSessionWebhook> {
+        let (url, expired_at_millis) = api::session_webhook_parts(message)?;
+        Ok(self.session_webhook(url, expired_at_millis))
     }
 
     pub(crate) fn webhook_http(&self) -> &HttpClient {
@@ -138,19 +194,40 @@ impl BlockingClient {
         self.inner.shared.enterprise_endpoint(segments)
     }
 
-    pub(crate) fn cached_access_token(&self, credentials: &AppCredentials) -> Option<String> {
-        self.inner.shared.cached_access_token(credentials)
+    pub(crate) fn cached_access_token(&self, cache_key: &str) -> Option<String> {
+        self.inner.shared.cached_access_token(cache_key)
     }
 
     pub(crate) fn store_access_token(
         &self,
-        credentials: &AppCredentials,
+        cache_key: &str,
         token: String,
         expires_in_seconds: Option<i64>,
     ) {
         self.inner
             .shared
-            .store_access_token(credentials, token, expires_in_seconds);
+            .store_access_token(cache_key, token, expires_in_seconds);
+    }
+
+    pub(crate) fn invalidate_access_token(&self, cache_key: &str, stale_token: &str) {
+        self.inner
+            .shared
+            .invalidate_access_token(cache_key, stale_token);
+    }
+
+    pub(crate) fn retry_on_invalid_token(&self) -> bool {
+        self.inner.shared.retry_on_invalid_token()
+    }
+
+    pub(crate) fn join_token_refresh(
+        &self,
+        cache_key: &str,
+    ) -> RefreshSlot<'_, std::sync::OnceLock<Result<String>>> {
+        self.inner.token_refreshes.join(cache_key)
+    }
+
+    pub(crate) fn token_endpoint(&self) -> crate::transport::TokenEndpoint {
+        self.inner.shared.token_endpoint()
     }
 
     pub(crate) fn body_snippet(&self) -> crate::transport::BodySnippetConfig {
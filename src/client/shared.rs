@@ -4,10 +4,10 @@ use reqx::{advanced::ClientProfile, prelude::RetryPolicy as ReqxRetryPolicy};
 use url::Url;
 
 use crate::{
-    auth::AppCredentials,
     error::Result,
     transport::{
         AccessTokenCache, BodySnippetConfig, DEFAULT_ENTERPRISE_BASE_URL, DEFAULT_WEBHOOK_BASE_URL,
+        TokenEndpoint,
     },
     util::url::{endpoint_url, normalize_base_url},
 };
@@ -32,6 +32,9 @@ pub(crate) struct BuilderConfig {
     pub(crate) retry_non_idempotent: bool,
     pub(crate) default_headers: Vec<(String, String)>,
     pub(crate) cache_access_token: bool,
+    pub(crate) access_token_store: Option<AccessTokenCache>,
+    pub(crate) retry_on_invalid_token: bool,
+    pub(crate) token_endpoint: TokenEndpoint,
     pub(crate) token_refresh_margin: Duration,
     pub(crate) body_snippet: BodySnippetConfig,
 }
@@ -51,6 +54,9 @@ impl Default for BuilderConfig {
             retry_non_idempotent: false,
             default_headers: Vec::new(),
             cache_access_token: true,
+            access_token_store: None,
+            retry_on_invalid_token: true,
+            token_endpoint: TokenEndpoint::default(),
             token_refresh_margin: DEFAULT_TOKEN_REFRESH_MARGIN,
             body_snippet: BodySnippetConfig::default(),
         }
@@ -84,23 +90,24 @@ pub(crate) struct SharedClientState {
     webhook_base_url: Url,
     enterprise_base_url: Url,
     access_token_cache: Option<AccessTokenCache>,
+    retry_on_invalid_token: bool,
+    token_endpoint: TokenEndpoint,
     token_refresh_margin: Duration,
     body_snippet: BodySnippetConfig,
 }
 
 impl SharedClientState {
-    pub(crate) fn new(
-        base_urls: BaseUrls,
-        cache_access_token: bool,
-        token_refresh_margin: Duration,
-        body_snippet: BodySnippetConfig,
-    ) -> Self {
+    pub(crate) fn new(base_urls: BaseUrls, config: &BuilderConfig) -> Self {
         Self {
             webhook_base_url: base_urls.webhook,
             enterprise_base_url: base_urls.enterprise,
-            access_token_cache: cache_access_token.then(AccessTokenCache::new),
-            token_refresh_margin,
-            body_snippet,
+            access_token_cache: config
+                .cache_access_token
+                .then(|| config.access_token_store.clone().unwrap_or_default()),
+            retry_on_invalid_token: config.retry_on_invalid_token,
+            token_endpoint: config.token_endpoint,
+            token_refresh_margin: config.token_refresh_margin,
+            body_snippet: config.body_snippet,
         }
     }
 
@@ -116,23 +123,37 @@ impl SharedClientState {
         endpoint_url(&self.enterprise_base_url, segments)
     }
 
-    pub(crate) fn cached_access_token(&self, credentials: &AppCredentials) -> Option<String> {
+    pub(crate) fn cached_access_token(&self, cache_key: &str) -> Option<String> {
         self.access_token_cache
             .as_ref()
-            .and_then(|cache| cache.get(credentials, self.token_refresh_margin))
+            .and_then(|cache| cache.get(cache_key, self.token_refresh_margin))
     }
 
     pub(crate) fn store_access_token(
         &self,
-        credentials: &AppCredentials,
+        cache_key: &str,
         token: String,
         expires_in_seconds: Option<i64>,
     ) {
         if let Some(cache) = &self.access_token_cache {
-            cache.store(credentials.clone(), token, expires_in_seconds);
+            cache.store(cache_key, token, expires_in_seconds);
         }
     }
 
+    pub(crate) fn invalidate_access_token(&self, cache_key: &str, stale_token: &str) {
+        if let Some(cache) = &self.access_token_cache {
+            cache.invalidate(cache_key, stale_token);
+        }
+    }
+
+    pub(crate) fn retry_on_invalid_token(&self) -> bool {
+        self.retry_on_invalid_token
+    }
+
+    pub(crate) fn token_endpoint(&self) -> TokenEndpoint {
+        self.token_endpoint
+    }
+
     pub(crate) fn body_snippet(&self) -> BodySnippetConfig {
         self.body_snippet
     }
@@ -237,6 +258,33 @@ macro_rules! impl_builder_methods {
                 self
             }
 
+            /// Sets the access-token store (defaults to an in-process map) and enables caching.
+            #[must_use]
+            pub fn access_token_store(
+                mut self,
+                store: impl crate::auth::AccessTokenStore + 'static,
+            ) -> Self {
+                self.config.access_token_store =
+                    Some(crate::transport::AccessTokenCache::new(store));
+                self.config.cache_access_token = true;
+                self
+            }
+
+            /// Enables or disables evicting the cached token and retrying once when
+            /// DingTalk rejects it as invalid or expired (enabled by default).
+            #[must_use]
+            pub fn retry_on_invalid_token(mut self, enabled: bool) -> Self {
+                self.config.retry_on_invalid_token = enabled;
+                self
+            }
+
+            /// Selects the endpoint used to obtain access tokens.
+            #[must_use]
+            pub fn token_endpoint(mut self, value: crate::transport::TokenEndpoint) -> Self {
+                self.config.token_endpoint = value;
+                self
+            }
+
             /// Sets refresh margin for cached access-token expiration.
             #[must_use]
             pub fn token_refresh_margin(mut self, value: std::time::Duration) -> Self {
@@ -0,0 +1,356 @@
+use std::fmt;
+
+use base64::Engine;
+use base64::alphabet;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig, STANDARD};
+use cbc::cipher::block_padding::NoPadding;
+use cbc::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use serde::Serialize;
+use sha1::{Digest, Sha1};
+use subtle::ConstantTimeEq;
+
+use crate::error::{Error, EventCryptoError, Result};
+use crate::types::DingTalkEvent;
+
+type Aes256CbcEnc = cbc::Encryptor<aes::Aes256>;
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+
+const AES_KEY_LEN: usize = 43;
+const RANDOM_PREFIX_LEN: usize = 16;
+const PADDING_BLOCK_SIZE: usize = 32;
+const SUCCESS_PLAINTEXT: &str = "success";
+
+// DingTalk-issued AES keys may carry non-zero trailing bits, which the strict
+// standard engine rejects.
+const AES_KEY_ENGINE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_allow_trailing_bits(true),
+);
+
+/// Encrypted reply body DingTalk expects from an HTTP event callback endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct EventCallbackResponse {
+    /// SHA-1 signature over token, timestamp, nonce, and `encrypt`.
+    pub msg_signature: String,
+    /// Millisecond timestamp echoed in the signature.
+    #[serde(rename = "timeStamp")]
+    pub time_stamp: String,
+    /// Nonce echoed in the signature.
+    pub nonce: String,
+    /// Base64 AES-CBC ciphertext.
+    pub encrypt: String,
+}
+
+/// Signature verification and AES-CBC crypto for DingTalk HTTP event callbacks.
+///
+/// `owner_key` is the app key, corp id, or suite key the subscription is bound
+/// to. `Debug` output redacts `token` and `aes_key`.
+#[derive(Clone)]
+pub struct EventCallbackCrypto {
+    token: String,
+    key: [u8; 32],
+    owner_key: String,
+}
+
+impl EventCallbackCrypto {
+    /// Creates event crypto from the subscription `token`, 43-character
+    /// `aes_key`, and owner key.
+    pub fn new(
+        token: impl Into<String>,
+        aes_key: impl AsRef<str>,
+        owner_key: impl Into<String>,
+    ) -> Result<Self> {
+        let aes_key = aes_key.as_ref();
+        if aes_key.len() != AES_KEY_LEN {
+            return Err(Error::EventCrypto(EventCryptoError::InvalidAesKey));
+        }
+        let key = AES_KEY_ENGINE
+            .decode(format!("{aes_key}="))
+            .ok()
+            .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            .ok_or(Error::EventCrypto(EventCryptoError::InvalidAesKey))?;
+
+        Ok(Self {
+            token: token.into(),
+            key,
+            owner_key: owner_key.into(),
+        })
+    }
+
+    /// Returns the owner key payloads are bound to.
+    #[must_use]
+    pub fn owner_key(&self) -> &str {
+        &self.owner_key
+    }
+
+    /// Computes the hex SHA-1 signature of the sorted token, timestamp, nonce, and `encrypt`.
+    #[must_use]
+    pub fn signature(&self, timestamp: &str, nonce: &str, encrypt: &str) -> String {
+        self.signature_digest(timestamp, nonce, encrypt)
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    /// Verifies the `signature` query parameter of an event callback.
+    pub fn verify_signature(
+        &self,
+        signature: &str,
+        timestamp: &str,
+        nonce: &str,
+        encrypt: &str,
+    ) -> Result<()> {
+        let provided = decode_hex(signature.trim())
+            .ok_or(Error::EventCrypto(EventCryptoError::SignatureMismatch))?;
+        let expected = self.signature_digest(timestamp, nonce, encrypt);
+        if bool::from(expected.as_slice().ct_eq(&provided)) {
+            Ok(())
+        } else {
+            Err(Error::EventCrypto(EventCryptoError::SignatureMismatch))
+        }
+    }
+
+    fn signature_digest(&self, timestamp: &str, nonce: &str, encrypt: &str) -> Vec<u8> {
+        let mut parts = [self.token.as_str(), timestamp, nonce, encrypt];
+        parts.sort_unstable();
+        Sha1::digest(parts.concat().as_bytes()).to_vec()
+    }
+
+    /// Verifies the signature and decrypts an event callback `encrypt` field.
+    pub fn decrypt_event(
+        &self,
+        signature: &str,
+        timestamp: &str,
+        nonce: &str,
+        encrypt: &str,
+    ) -> Result<String> {
+        self.verify_signature(signature, timestamp, nonce, encrypt)?;
+        self.decrypt(encrypt)
+    }
+
+    /// Verifies, decrypts, and parses an event callback into a [`DingTalkEvent`].
+    pub fn decrypt_typed_event(
+        &self,
+        signature: &str,
+        timestamp: &str,
+        nonce: &str,
+        encrypt: &str,
+    ) -> Result<DingTalkEvent> {
+        let payload = self.decrypt_event(signature, timestamp, nonce, encrypt)?;
+        Ok(serde_json::from_str(&payload)?)
+    }
+
+    /// Decrypts a Base64 `encrypt` field without checking its signature.
+    pub fn decrypt(&self, encrypt: &str) -> Result<String> {
+        let ciphertext = STANDARD
+            .decode(encrypt.trim())
+            .map_err(|_| Error::EventCrypto(EventCryptoError::InvalidCiphertext))?;
+        let plaintext = Aes256CbcDec::new(&self.key.into(), &self.iv().into())
+            .decrypt_padded_vec_mut::<NoPadding>(&ciphertext)
+            .map_err(|_| Error::EventCrypto(EventCryptoError::InvalidCiphertext))?;
+
+        let invalid_payload = || Error::EventCrypto(EventCryptoError::InvalidPayload);
+        let padding = usize::from(*plaintext.last().ok_or_else(invalid_payload)?);
+        if !(1..=PADDING_BLOCK_SIZE).contains(&padding) || padding > plaintext.len() {
+            return Err(invalid_payload());
+        }
+        let content = &plaintext[..plaintext.len() - padding];
+
+        let header_len = RANDOM_PREFIX_LEN + 4;
+        let length_bytes = content
+            .get(RANDOM_PREFIX_LEN..header_len)
+            .ok_or_else(invalid_payload)?;
+        let message_len =
+            u32::from_be_bytes(length_bytes.try_into().map_err(|_| invalid_payload())?);
+        let message_end = usize::try_from(message_len)
+            .ok()
+            .and_then(|len| header_len.checked_add(len))
+            .filter(|end| *end <= content.len())
+            .ok_or_else(invalid_payload)?;
+
+        if &content[message_end..] != self.owner_key.as_bytes() {
+            return Err(Error::EventCrypto(EventCryptoError::OwnerKeyMismatch));
+        }
+        String::from_utf8(content[header_len..message_end].to_vec()).map_err(|_| invalid_payload())
+    }
+
+    /// Encrypts `plaintext` into a Base64 `encrypt` field bound to the owner key.
+    pub fn encrypt(&self, plaintext: &str) -> Result<String> {
+        let mut random = [0_u8; RANDOM_PREFIX_LEN];
+        getrandom::getrandom(&mut random).map_err(|error| Error::InvalidConfig {
+            message: format!("Failed to generate random bytes: {error}"),
+            source: None,
+        })?;
+        self.encrypt_with_random(plaintext, random)
+    }
+
+    /// Builds the encrypted `success` reply DingTalk requires from callback endpoints.
+    pub fn success_response(
+        &self,
+        timestamp: impl Into<String>,
+        nonce: impl Into<String>,
+    ) -> Result<EventCallbackResponse> {
+        let time_stamp = timestamp.into();
+        let nonce = nonce.into();
+        let encrypt = self.encrypt(SUCCESS_PLAINTEXT)?;
+        Ok(EventCallbackResponse {
+            msg_signature: self.signature(&time_stamp, &nonce, &encrypt),
+            time_stamp,
+            nonce,
+            encrypt,
+        })
+    }
+
+    fn encrypt_with_random(
+        &self,
+        plaintext: &str,
+        random: [u8; RANDOM_PREFIX_LEN],
+    ) -> Result<String> {
+        let message_len = u32::try_from(plaintext.len())
+            .map_err(|_| Error::EventCrypto(EventCryptoError::InvalidPayload))?;
+
+        let mut buffer = Vec::with_capacity(
+            RANDOM_PREFIX_LEN + 4 + plaintext.len() + self.owner_key.len() + PADDING_BLOCK_SIZE,
+        );
+        buffer.extend_from_slice(&random);
+        buffer.extend_from_slice(&message_len.to_be_bytes());
+        buffer.extend_from_slice(plaintext.as_bytes());
+        buffer.extend_from_slice(self.owner_key.as_bytes());
+        let padding = PADDING_BLOCK_SIZE - buffer.len() % PADDING_BLOCK_SIZE;
+        buffer.resize(buffer.len() + padding, padding as u8);
+
+        let ciphertext = Aes256CbcEnc::new(&self.key.into(), &self.iv().into())
+            .encrypt_padded_vec_mut::<NoPadding>(&buffer);
+        Ok(STANDARD.encode(ciphertext))
+    }
+
+    fn iv(&self) -> [u8; 16] {
+        let mut iv = [0_u8; 16];
+        iv.copy_from_slice(&self.key[..16]);
+        iv
+    }
+}
+
+impl fmt::Debug for EventCallbackCrypto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventCallbackCrypto")
+            .field("token", &"<redacted>")
+            .field("aes_key", &"<redacted>")
+            .field("owner_key", &self.owner_key)
+            .finish()
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `AES_KEY`, `OWNER_KEY`, `NONCE`, and `ENCRYPT` come from DingTalk's published
+    // callback crypto demo.
+    const TOKEN: &str = "123456";
+    const AES_KEY: &str = "o1w0aum42yaptlz8alnhwikjd3jenzt9cb9wmzptgus";
+    const OWNER_KEY: &str = "dingxxxxxx";
+    const TIMESTAMP: &str = "1605695694141";
+    const NONCE: &str = "WelUQl6bh4s9VPIE";
+    const ENCRYPT: &str =
+        "X1VSe9cTJUMZu60d3kyLYTrBq5578ZRJtteU94wG0Q4Uk6E/wQYeJRIC0/UFW5Wkya1Ihz9oXAdLlyC9TRaqsQ==";
+    const SIGNATURE: &str = "5e163aeb56268c78dc44e4308aa491f0e72bd339";
+
+    fn crypto() -> EventCallbackCrypto {
+        EventCallbackCrypto::new(TOKEN, AES_KEY, OWNER_KEY).expect("crypto")
+    }
+
+    #[test]
+    fn matches_published_sample() {
+        let crypto = crypto();
+        assert_eq!(crypto.signature(TIMESTAMP, NONCE, ENCRYPT), SIGNATURE);
+        assert_eq!(
+            crypto
+                .decrypt_event(SIGNATURE, TIMESTAMP, NONCE, ENCRYPT)
+                .expect("decrypt"),
+            "success"
+        );
+        assert_eq!(
+            crypto
+                .encrypt_with_random("success", *b"WelUQl6bCqcBa2fM")
+                .expect("encrypt"),
+            ENCRYPT
+        );
+        crypto
+            .verify_signature(&SIGNATURE.to_ascii_uppercase(), TIMESTAMP, NONCE, ENCRYPT)
+            .expect("hex case should not matter");
+    }
+
+    #[test]
+    fn typed_event_round_trips() {
+        let crypto = crypto();
+        let encrypt = crypto
+            .encrypt(r#"{"EventType":"check_url"}"#)
+            .expect("encrypt");
+        let signature = crypto.signature(TIMESTAMP, NONCE, &encrypt);
+        assert!(matches!(
+            crypto
+                .decrypt_typed_event(&signature, TIMESTAMP, NONCE, &encrypt)
+                .expect("typed event"),
+            DingTalkEvent::CheckUrl
+        ));
+    }
+
+    #[test]
+    fn success_response_round_trips() {
+        let crypto = crypto();
+        let response = crypto.success_response(TIMESTAMP, NONCE).expect("response");
+        assert_eq!(
+            crypto
+                .decrypt_event(
+                    &response.msg_signature,
+                    &response.time_stamp,
+                    &response.nonce,
+                    &response.encrypt,
+                )
+                .expect("decrypt"),
+            "success"
+        );
+
+        let json = serde_json::to_value(&response).expect("serialize");
+        assert_eq!(json["timeStamp"], TIMESTAMP);
+        assert!(json.get("msg_signature").is_some());
+    }
+
+    #[test]
+    fn rejects_bad_signature_owner_and_key() {
+        let error = crypto()
+            .decrypt_event("deadbeef", TIMESTAMP, NONCE, ENCRYPT)
+            .expect_err("signature should not verify");
+        assert!(matches!(
+            error,
+            Error::EventCrypto(EventCryptoError::SignatureMismatch)
+        ));
+
+        let other_owner = EventCallbackCrypto::new(TOKEN, AES_KEY, "dingother").expect("crypto");
+        let error = other_owner
+            .decrypt(ENCRYPT)
+            .expect_err("owner should not match");
+        assert!(matches!(
+            error,
+            Error::EventCrypto(EventCryptoError::OwnerKeyMismatch)
+        ));
+
+        let error = EventCallbackCrypto::new(TOKEN, "short", OWNER_KEY).expect_err("bad key");
+        assert!(matches!(
+            error,
+            Error::EventCrypto(EventCryptoError::InvalidAesKey)
+        ));
+    }
+}
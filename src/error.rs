@@ -76,6 +76,42 @@ impl fmt::Display for TransportError {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[non_exhaustive]
+/// Reason an incoming callback signature was rejected.
+pub enum CallbackSignatureError {
+    /// `timestamp` is not a millisecond Unix timestamp.
+    #[error("invalid timestamp")]
+    InvalidTimestamp,
+    /// `timestamp` is outside the accepted tolerance window.
+    #[error("stale timestamp")]
+    StaleTimestamp,
+    /// `sign` does not match the expected signature.
+    #[error("signature mismatch")]
+    Mismatch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[non_exhaustive]
+/// Reason an encrypted event callback could not be processed.
+pub enum EventCryptoError {
+    /// `aes_key` is not a 43-character Base64 encoded 256-bit key.
+    #[error("invalid aes key")]
+    InvalidAesKey,
+    /// `signature` does not match the expected signature.
+    #[error("signature mismatch")]
+    SignatureMismatch,
+    /// `encrypt` is not valid Base64 AES-CBC ciphertext.
+    #[error("invalid ciphertext")]
+    InvalidCiphertext,
+    /// Decrypted payload framing is malformed.
+    #[error("invalid payload")]
+    InvalidPayload,
+    /// Decrypted payload is bound to a different app/corp/suite key.
+    #[error("owner key mismatch")]
+    OwnerKeyMismatch,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 /// Stable high-level error category.
@@ -98,6 +134,12 @@ pub enum ErrorKind {
     Timestamp,
     /// Signature generation failure.
     Signature,
+    /// Incoming callback signature verification failure.
+    CallbackSignature,
+    /// Event callback signature, encryption, or decryption failure.
+    EventCrypto,
+    /// Robot callback `sessionWebhook` has expired.
+    SessionExpired,
     /// Invalid SDK configuration.
     InvalidConfig,
 }
@@ -156,6 +198,21 @@ pub enum Error {
     #[error("Signature generation failed")]
     Signature,
 
+    /// Incoming callback signature verification error.
+    #[error("Callback signature verification failed: {0}")]
+    CallbackSignature(CallbackSignatureError),
+
+    /// Event callback crypto error.
+    #[error("Event callback crypto failed: {0}")]
+    EventCrypto(EventCryptoError),
+
+    /// Robot callback `sessionWebhook` expired before sending.
+    #[error("Session webhook expired at {expired_at_millis}")]
+    SessionExpired {
+        /// Expiry as a millisecond Unix timestamp.
+        expired_at_millis: i64,
+    },
+
     /// Invalid runtime configuration.
     #[error("Invalid configuration: {message}")]
     InvalidConfig {
@@ -171,6 +228,8 @@ fn reqx_timeout_phase_name(error: &reqx::Error) -> Option<&'static str> {
         reqx::Error::Timeout { phase, .. } => Some(match phase {
             reqx::TimeoutPhase::Transport => "transport",
             reqx::TimeoutPhase::ResponseBody => "response_body",
+            // `TimeoutPhase` is `#[non_exhaustive]`; newer reqx phases map to "other".
+            _ => "other",
         }),
         _ => None,
     }
@@ -183,7 +242,8 @@ fn reqx_transport_kind_name(error: &reqx::Error) -> Option<&'static str> {
             reqx::TransportErrorKind::Connect => "connect",
             reqx::TransportErrorKind::Tls => "tls",
             reqx::TransportErrorKind::Read => "read",
-            reqx::TransportErrorKind::Other => "other",
+            // `TransportErrorKind` is `#[non_exhaustive]`; `Other` and newer kinds map here.
+            _ => "other",
         }),
         _ => None,
     }
@@ -268,6 +328,9 @@ impl Error {
             Self::Serialization(_) => ErrorKind::Serialization,
             Self::Timestamp(_) => ErrorKind::Timestamp,
             Self::Signature => ErrorKind::Signature,
+            Self::CallbackSignature(_) => ErrorKind::CallbackSignature,
+            Self::EventCrypto(_) => ErrorKind::EventCrypto,
+            Self::SessionExpired { .. } => ErrorKind::SessionExpired,
             Self::InvalidConfig { .. } => ErrorKind::InvalidConfig,
         }
     }
@@ -337,4 +400,56 @@ impl Error {
             _ => None,
         }
     }
+
+    /// Returns an equivalent error for callers sharing one failed operation.
+    ///
+    /// Library sources that cannot be cloned are preserved as their message.
+    #[cfg(any(feature = "_async", feature = "_blocking"))]
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            Self::Api {
+                code,
+                message,
+                request_id,
+                body_snippet,
+            } => Self::Api {
+                code: *code,
+                message: message.clone(),
+                request_id: request_id.clone(),
+                body_snippet: body_snippet.clone(),
+            },
+            Self::Auth(error) => Self::Auth(error.clone()),
+            Self::NotFound(error) => Self::NotFound(error.clone()),
+            Self::Conflict(error) => Self::Conflict(error.clone()),
+            Self::RateLimited { error, retry_after } => Self::RateLimited {
+                error: error.clone(),
+                retry_after: *retry_after,
+            },
+            Self::Transport(error) => Self::Transport(error.clone()),
+            Self::Serialization(error) => {
+                Self::Serialization(serde::de::Error::custom(error.to_string()))
+            }
+            Self::Timestamp(error) => {
+                // `SystemTimeError` can only be built by comparing two instants.
+                let later = SystemTime::UNIX_EPOCH + error.duration();
+                match SystemTime::UNIX_EPOCH.duration_since(later) {
+                    Err(error) => Self::Timestamp(error),
+                    Ok(_) => Self::Serialization(serde::de::Error::custom(error.to_string())),
+                }
+            }
+            Self::Signature => Self::Signature,
+            Self::CallbackSignature(error) => Self::CallbackSignature(*error),
+            Self::EventCrypto(error) => Self::EventCrypto(*error),
+            Self::SessionExpired { expired_at_millis } => Self::SessionExpired {
+                expired_at_millis: *expired_at_millis,
+            },
+            Self::InvalidConfig { message, source } => Self::InvalidConfig {
+                message: match source {
+                    Some(source) => format!("{message}: {source}"),
+                    None => message.clone(),
+                },
+                source: None,
+            },
+        }
+    }
 }
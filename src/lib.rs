@@ -6,6 +6,7 @@
 //! This crate provides:
 //! - async client (`Client`) and services (`WebhookService`, `EnterpriseService`)
 //! - optional blocking client (`BlockingClient`) and services
+//! - optional Stream Mode WebSocket client (`StreamClient`, `stream` feature)
 //! - typed request/response models for contacts and approvals
 //! - unified error model and transport profiles/retry integration via `reqx`
 //!
@@ -42,6 +43,9 @@
 //!
 //! In normal usage, select TLS features directly. They automatically enable the
 //! matching runtime mode.
+//!
+//! `stream` adds the async Stream Mode client; its `wss` connections use the
+//! selected async TLS backend.
 
 // Require at least one client mode.
 #[cfg(not(any(feature = "_async", feature = "_blocking")))]
@@ -123,6 +127,7 @@ compile_error!("Blocking TLS features require enabling `_blocking`.");
 mod api;
 mod auth;
 mod client;
+mod crypto;
 mod error;
 mod signature;
 mod transport;
@@ -131,10 +136,16 @@ mod util;
 
 #[cfg(feature = "_blocking")]
 #[cfg_attr(docsrs, doc(cfg(feature = "_blocking")))]
-pub use api::{BlockingEnterpriseService, BlockingWebhookService};
+pub use api::{
+    BlockingEnterpriseService, BlockingOAuthService, BlockingOrgTreeWalker, BlockingSessionWebhook,
+    BlockingWebhookService,
+};
 #[cfg(feature = "_async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "_async")))]
-pub use api::{EnterpriseService, WebhookService};
+pub use api::{EnterpriseService, OAuthService, OrgTreeWalker, SessionWebhook, WebhookService};
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub use api::{StreamClient, StreamHandler};
 #[cfg(feature = "_async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "_async")))]
 pub use client::async_client::{Client, ClientBuilder};
@@ -147,30 +158,69 @@ pub use client::blocking_client::{BlockingClient, BlockingClientBuilder};
 /// Blocking runtime service aliases.
 pub mod blocking {
     pub use crate::{
-        BlockingEnterpriseService as EnterpriseService, BlockingWebhookService as WebhookService,
+        BlockingEnterpriseService as EnterpriseService, BlockingOAuthService as OAuthService,
+        BlockingOrgTreeWalker as OrgTreeWalker, BlockingSessionWebhook as SessionWebhook,
+        BlockingWebhookService as WebhookService,
     };
 }
 
-/// Application credentials used by enterprise APIs.
-pub use auth::AppCredentials;
+/// Application credentials and access-token storage used by enterprise APIs.
+pub use auth::{
+    AccessTokenStore, AppCredentials, CachedAccessToken, InMemoryAccessTokenStore,
+    InMemorySuiteTicketStore, SuiteCredentials, SuiteTicketProvider,
+};
+/// HTTP event callback signature verification and AES-CBC crypto.
+pub use crypto::{EventCallbackCrypto, EventCallbackResponse};
 /// SDK error type and helpers.
-pub use error::{Error, ErrorKind, HttpError, Result, TransportError};
+pub use error::{
+    CallbackSignatureError, Error, ErrorKind, EventCryptoError, HttpError, Result, TransportError,
+};
 /// reqx transport profile presets for DingTalk clients.
 pub use reqx::advanced::ClientProfile;
 /// reqx retry policy for DingTalk clients.
 pub use reqx::prelude::RetryPolicy;
+/// Robot callback signature verification and JSAPI `dd.config` signing.
+pub use signature::{DEFAULT_CALLBACK_TOLERANCE, jsapi_signature, verify_callback_signature};
 /// Controls whether and how response snippets are retained in errors.
 pub use transport::BodySnippetConfig;
+/// Endpoint used to obtain enterprise access tokens.
+pub use transport::TokenEndpoint;
 /// Public webhook and enterprise request/response helper types.
 pub use types::{
-    ActionCardButton, ApprovalCreateProcessInstanceRequest, ApprovalFormComponentValue,
-    ApprovalListProcessInstanceIdsRequest, ApprovalListProcessInstanceIdsResult,
-    ApprovalProcessInstance, ApprovalTerminateProcessInstanceRequest,
-    ContactCreateDepartmentRequest, ContactCreateDepartmentResult, ContactCreateUserRequest,
-    ContactCreateUserResult, ContactDeleteDepartmentRequest, ContactDeleteUserRequest,
-    ContactDepartment, ContactGetDepartmentRequest, ContactGetUserByMobileRequest,
-    ContactGetUserByUnionIdRequest, ContactGetUserRequest, ContactListSubDepartmentIdsRequest,
-    ContactListSubDepartmentIdsResult, ContactListSubDepartmentsRequest,
-    ContactListSubDepartmentsResult, ContactListUsersRequest, ContactListUsersResult,
-    ContactUpdateDepartmentRequest, ContactUpdateUserRequest, ContactUser, FeedCardLink,
+    ActionCardButton, ActionCardMessage, ApprovalChangeEvent, ApprovalCreateProcessInstanceRequest,
+    ApprovalFormComponentValue, ApprovalListProcessInstanceIdsRequest,
+    ApprovalListProcessInstanceIdsResult, ApprovalProcessInstance,
+    ApprovalTerminateProcessInstanceRequest, ContactAdmin, ContactAdminScope,
+    ContactCountUsersRequest, ContactCountUsersResult, ContactCreateDepartmentRequest,
+    ContactCreateDepartmentResult, ContactCreateExternalContactRequest, ContactCreateUserRequest,
+    ContactCreateUserResult, ContactDeleteDepartmentRequest, ContactDeleteExternalContactRequest,
+    ContactDeleteUserRequest, ContactDepartment, ContactDepartmentEvent, ContactDeptLeader,
+    ContactDeptOrder, ContactDeptTitle, ContactEmpLeaveRecord, ContactEmpLeaveRecordsResult,
+    ContactExternal, ContactExternalLabel, ContactExternalLabelGroup, ContactGetAdminScopeRequest,
+    ContactGetDepartmentRequest, ContactGetExternalContactRequest, ContactGetUserByMobileRequest,
+    ContactGetUserByUnionIdRequest, ContactGetUserRequest, ContactListEmpLeaveRecordsRequest,
+    ContactListExternalContactsRequest, ContactListExternalLabelGroupsRequest,
+    ContactListInactiveUsersRequest, ContactListInactiveUsersResult,
+    ContactListParentDepartmentsByDeptRequest, ContactListParentDepartmentsByUserRequest,
+    ContactListSimpleUsersRequest, ContactListSimpleUsersResult,
+    ContactListSubDepartmentIdsRequest, ContactListSubDepartmentIdsResult,
+    ContactListSubDepartmentsRequest, ContactListSubDepartmentsResult, ContactListUserIdsRequest,
+    ContactListUserIdsResult, ContactListUsersRequest, ContactListUsersResult, ContactSimpleUser,
+    ContactUpdateDepartmentRequest, ContactUpdateExternalContactRequest, ContactUpdateUserRequest,
+    ContactUser, ContactUserEvent, ContactUserRole, DepartmentMove, DingTalkEvent, FeedCardLink,
+    FeedCardMessage, FieldChange, GroupMessageSendResponse, JsapiTicket, LinkMessage,
+    MarkdownMessage, OAuthAuthorizeRequest, OAuthUserProfile, OrgDepartment, OrgDiff, OrgSnapshot,
+    OrgUser, OtoBatchSendResponse, ReplyMessageResponse, RobotAtUser, RobotAudioContent,
+    RobotCallbackMessage, RobotFileContent, RobotMessageContent, RobotPictureContent,
+    RobotRichTextContent, RobotRichTextItem, RobotTextContent, Role, RoleAssignmentRequest,
+    RoleGetRoleGroupRequest, RoleGetRoleRequest, RoleGroup, RoleListRequest, RoleListResult,
+    RoleListUsersRequest, RoleListUsersResult, RoleManageScope, RoleUser, TextMessage,
+    UserAccessToken, UserChange, UserIdentity, WebhookAt, WebhookMessage, WebhookSendResponse,
+};
+/// Stream Mode frame and subscription types.
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub use types::{
+    STREAM_TOPIC_CARD_CALLBACK, STREAM_TOPIC_ROBOT_MESSAGE, StreamAck, StreamFrame,
+    StreamFrameType, StreamHeaders, StreamSubscription,
 };
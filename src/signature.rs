@@ -1,12 +1,16 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use crate::error::Error;
 use crate::error::Result;
+use crate::error::{CallbackSignatureError, Error};
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD;
 use hmac::{Hmac, Mac};
+use sha1::{Digest, Sha1};
 use sha2::Sha256;
 
+/// Default accepted clock skew for incoming robot callbacks (one hour, per DingTalk docs).
+pub const DEFAULT_CALLBACK_TOLERANCE: Duration = Duration::from_secs(3_600);
+
 pub(crate) fn current_timestamp_millis() -> Result<String> {
     Ok(SystemTime::now()
         .duration_since(UNIX_EPOCH)?
@@ -14,9 +18,12 @@ pub(crate) fn current_timestamp_millis() -> Result<String> {
         .to_string())
 }
 
+fn hmac_sha256(secret: &str) -> Result<Hmac<Sha256>> {
+    Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|_| Error::Signature)
+}
+
 fn sign_hmac_sha256(secret: &str, content: &str) -> Result<Vec<u8>> {
-    let mut mac =
-        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|_| Error::Signature)?;
+    let mut mac = hmac_sha256(secret)?;
     mac.update(content.as_bytes());
     Ok(mac.finalize().into_bytes().to_vec())
 }
@@ -28,16 +35,173 @@ pub(crate) fn create_signature(timestamp: &str, secret: &str) -> Result<String>
     Ok(urlencoding::encode(&signature_base64).into_owned())
 }
 
+/// Verifies the `timestamp` and `sign` headers DingTalk attaches to robot callbacks.
+///
+/// `sign` is the Base64 HMAC-SHA256 of `"{timestamp}\n{app_secret}"` keyed by
+/// `app_secret`. Callbacks whose timestamp differs from `now` by more than
+/// `tolerance` are rejected as stale; see [`DEFAULT_CALLBACK_TOLERANCE`].
+pub fn verify_callback_signature(
+    timestamp: &str,
+    sign: &str,
+    app_secret: &str,
+    now: SystemTime,
+    tolerance: Duration,
+) -> Result<()> {
+    let timestamp_millis = timestamp
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| Error::CallbackSignature(CallbackSignatureError::InvalidTimestamp))?;
+    let signed_at = UNIX_EPOCH
+        .checked_add(Duration::from_millis(timestamp_millis))
+        .ok_or(Error::CallbackSignature(
+            CallbackSignatureError::InvalidTimestamp,
+        ))?;
+    let skew = now
+        .duration_since(signed_at)
+        .or_else(|_| signed_at.duration_since(now))
+        .unwrap_or(Duration::MAX);
+    if skew > tolerance {
+        return Err(Error::CallbackSignature(
+            CallbackSignatureError::StaleTimestamp,
+        ));
+    }
+
+    let expected = STANDARD
+        .decode(sign.trim())
+        .map_err(|_| Error::CallbackSignature(CallbackSignatureError::Mismatch))?;
+    let mut mac = hmac_sha256(app_secret)?;
+    mac.update(format!("{}\n{app_secret}", timestamp.trim()).as_bytes());
+    mac.verify_slice(&expected)
+        .map_err(|_| Error::CallbackSignature(CallbackSignatureError::Mismatch))
+}
+
+/// Computes the `dd.config` signature for a JSAPI ticket.
+///
+/// The signature is the hex SHA-1 of
+/// `"jsapi_ticket={ticket}&noncestr={nonce_str}&timestamp={timestamp}&url={url}"`;
+/// `url` is the page URL without its `#` fragment and with its query
+/// percent-decoded, as DingTalk's reference implementation does.
+#[must_use]
+pub fn jsapi_signature(ticket: &str, nonce_str: &str, timestamp: &str, url: &str) -> String {
+    let url = url.split_once('#').map_or(url, |(url, _)| url);
+    let url = match url.split_once('?') {
+        Some((base, query)) => {
+            let query = query.replace('+', " ");
+            let query = urlencoding::decode(&query)
+                .map_or_else(|_| query.clone(), |decoded| decoded.into_owned());
+            format!("{base}?{query}")
+        }
+        None => url.to_string(),
+    };
+    let plain =
+        format!("jsapi_ticket={ticket}&noncestr={nonce_str}&timestamp={timestamp}&url={url}");
+    Sha1::digest(plain.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const TIMESTAMP: &str = "1700000000000";
+    const SIGN: &str = "OuzzJR5+xZ4/EYwqtNt6sMYZQMTa/HEGvc9miJe7XzY=";
+
+    fn at_millis(millis: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(millis)
+    }
+
     #[test]
     fn creates_expected_signature() {
-        let signature = create_signature("1700000000000", "secret").expect("signature");
+        let signature = create_signature(TIMESTAMP, "secret").expect("signature");
         assert_eq!(
             signature,
             "OuzzJR5%2BxZ4%2FEYwqtNt6sMYZQMTa%2FHEGvc9miJe7XzY%3D"
         );
     }
+
+    #[test]
+    fn verifies_matching_callback_signature() {
+        verify_callback_signature(
+            TIMESTAMP,
+            SIGN,
+            "secret",
+            at_millis(1_700_000_060_000),
+            DEFAULT_CALLBACK_TOLERANCE,
+        )
+        .expect("signature should verify");
+    }
+
+    #[test]
+    fn rejects_mismatched_callback_signature() {
+        let error = verify_callback_signature(
+            TIMESTAMP,
+            SIGN,
+            "other-secret",
+            at_millis(1_700_000_000_000),
+            DEFAULT_CALLBACK_TOLERANCE,
+        )
+        .expect_err("signature should not verify");
+        assert!(matches!(
+            error,
+            Error::CallbackSignature(CallbackSignatureError::Mismatch)
+        ));
+    }
+
+    #[test]
+    fn rejects_stale_or_invalid_callback_timestamp() {
+        let stale = verify_callback_signature(
+            TIMESTAMP,
+            SIGN,
+            "secret",
+            at_millis(1_700_000_000_000 + 3_600_001),
+            DEFAULT_CALLBACK_TOLERANCE,
+        )
+        .expect_err("timestamp should be stale");
+        assert!(matches!(
+            stale,
+            Error::CallbackSignature(CallbackSignatureError::StaleTimestamp)
+        ));
+
+        let invalid = verify_callback_signature(
+            "not-a-number",
+            SIGN,
+            "secret",
+            at_millis(1_700_000_000_000),
+            DEFAULT_CALLBACK_TOLERANCE,
+        )
+        .expect_err("timestamp should be invalid");
+        assert!(matches!(
+            invalid,
+            Error::CallbackSignature(CallbackSignatureError::InvalidTimestamp)
+        ));
+    }
+
+    #[test]
+    fn jsapi_signature_ignores_url_fragment() {
+        let expected = "98fe280723b199e1e12afae4c0b17d7f34c469f7";
+        let url = "https://h5.example.com/app?x=1";
+        assert_eq!(
+            jsapi_signature("ticket-1", "nonce-1", "1700000000", url),
+            expected
+        );
+        assert_eq!(
+            jsapi_signature("ticket-1", "nonce-1", "1700000000", &format!("{url}#/home")),
+            expected
+        );
+    }
+
+    #[test]
+    fn jsapi_signature_decodes_url_query() {
+        assert_eq!(
+            jsapi_signature(
+                "ticket-1",
+                "nonce-1",
+                "1700000000",
+                "https://h5.example.com/app?next=%2Fhome&q=a%3Db+%E4%B8%AD",
+            ),
+            "f0022b7ad3f5d95f0db2284f371032c1f975702e"
+        );
+    }
 }
@@ -1,21 +1,24 @@
 use std::{
     collections::HashMap,
-    sync::{Arc, RwLock},
-    time::{Duration, Instant, SystemTime},
+    fmt,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use serde::de::DeserializeOwned;
 use url::Url;
 
 use crate::{
-    auth::AppCredentials,
+    auth::{AccessTokenStore, CachedAccessToken, InMemoryAccessTokenStore},
     error::{Error, HttpError, Result, TransportError},
     types::{
         enterprise::ApprovalProcessInstance,
         internal::{
             ApprovalCreateProcessInstanceResponse, ApprovalGetProcessInstanceResponse,
-            GetTokenResponse, StandardApiResponse, TopApiResultResponse, TopApiSimpleResponse,
+            GetTokenResponse, RawBodyResponse, StandardApiResponse, TopApiResultResponse,
+            TopApiSimpleResponse,
         },
+        oauth::OAuthAuthorizeRequest,
     },
     util::{
         redact::{redact_text, truncate_snippet},
@@ -25,6 +28,7 @@ use crate::{
 
 pub(crate) const DEFAULT_WEBHOOK_BASE_URL: &str = "https://oapi.dingtalk.com";
 pub(crate) const DEFAULT_ENTERPRISE_BASE_URL: &str = "https://api.dingtalk.com";
+pub(crate) const DEFAULT_OAUTH_LOGIN_URL: &str = "https://login.dingtalk.com/oauth2/auth";
 pub(crate) const DEFAULT_MSG_KEY: &str = "sampleMarkdown";
 const DEFAULT_ACCESS_TOKEN_TTL: Duration = Duration::from_secs(7_200);
 const MIN_ACCESS_TOKEN_TTL: Duration = Duration::from_secs(30);
@@ -47,6 +51,18 @@ impl Default for BodySnippetConfig {
     }
 }
 
+/// Endpoint used to obtain enterprise access tokens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TokenEndpoint {
+    /// Legacy `GET /gettoken?appkey=..&appsecret=..` on the webhook base URL.
+    #[default]
+    Legacy,
+    /// `POST /v1.0/oauth2/accessToken` on the enterprise base URL; the app
+    /// secret is sent in the JSON body instead of the URL.
+    OAuth2,
+}
+
 pub(crate) fn api_error(
     code: i64,
     message: impl Into<String>,
@@ -76,6 +92,15 @@ pub(crate) fn response_api_error(
     )
 }
 
+/// Returns `true` for errors DingTalk reports when an access token is invalid or expired.
+pub(crate) fn is_invalid_token_error(error: &Error) -> bool {
+    match error {
+        Error::Api { code, .. } => matches!(*code, 40001 | 40014 | 42001),
+        Error::Auth(error) => error.status == 401,
+        _ => false,
+    }
+}
+
 pub(crate) fn build_webhook_url(base_url: &Url, token: &str, secret: Option<&str>) -> Result<Url> {
     let mut url = endpoint_url(base_url, &["robot", "send"])?;
     {
@@ -92,52 +117,178 @@ pub(crate) fn build_webhook_url(base_url: &Url, token: &str, secret: Option<&str
     Ok(url)
 }
 
-#[derive(Debug, Clone)]
+pub(crate) fn build_session_webhook_url(url: &str, expired_at_millis: Option<i64>) -> Result<Url> {
+    if let Some(expired_at_millis) = expired_at_millis {
+        let now_millis = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        if i128::try_from(now_millis).unwrap_or(i128::MAX) >= i128::from(expired_at_millis) {
+            return Err(Error::SessionExpired { expired_at_millis });
+        }
+    }
+
+    Url::parse(url).map_err(|source| Error::InvalidConfig {
+        message: "Invalid session webhook URL".to_string(),
+        source: Some(Box::new(source)),
+    })
+}
+
+pub(crate) fn build_oauth_authorize_url(
+    client_id: &str,
+    request: &OAuthAuthorizeRequest,
+) -> Result<Url> {
+    let mut url = Url::parse(DEFAULT_OAUTH_LOGIN_URL).map_err(|source| Error::InvalidConfig {
+        message: "Invalid OAuth login URL".to_string(),
+        source: Some(Box::new(source)),
+    })?;
+    {
+        let mut query = url.query_pairs_mut();
+        query.append_pair("redirect_uri", &request.redirect_uri);
+        query.append_pair("response_type", "code");
+        query.append_pair("client_id", client_id);
+        query.append_pair("scope", &request.scope);
+        if let Some(state) = &request.state {
+            query.append_pair("state", state);
+        }
+        query.append_pair("prompt", &request.prompt);
+        if let Some(corp_id) = &request.corp_id {
+            query.append_pair("corpId", corp_id);
+        }
+    }
+    Ok(url)
+}
+
+/// Access-token cache backed by a pluggable [`AccessTokenStore`].
+#[derive(Clone)]
 pub(crate) struct AccessTokenCache {
-    inner: Arc<RwLock<HashMap<AppCredentials, CachedAccessToken>>>,
+    store: Arc<dyn AccessTokenStore>,
 }
 
-#[derive(Debug, Clone)]
-struct CachedAccessToken {
-    token: String,
-    expires_at: Instant,
+impl fmt::Debug for AccessTokenCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessTokenCache").finish_non_exhaustive()
+    }
+}
+
+impl Default for AccessTokenCache {
+    fn default() -> Self {
+        Self::new(InMemoryAccessTokenStore::new())
+    }
 }
 
 impl AccessTokenCache {
     #[must_use]
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(store: impl AccessTokenStore + 'static) -> Self {
         Self {
-            inner: Arc::new(RwLock::new(HashMap::new())),
+            store: Arc::new(store),
         }
     }
 
-    pub(crate) fn get(
-        &self,
-        credentials: &AppCredentials,
-        refresh_margin: Duration,
-    ) -> Option<String> {
-        let now = Instant::now();
-        let guard = self.inner.read().ok()?;
-        let cached = guard.get(credentials)?;
-        let refresh_at = now.checked_add(refresh_margin)?;
+    pub(crate) fn get(&self, key: &str, refresh_margin: Duration) -> Option<String> {
+        let cached = self.store.get(key)?;
+        let refresh_at = SystemTime::now().checked_add(refresh_margin)?;
         if refresh_at < cached.expires_at {
-            Some(cached.token.clone())
+            Some(cached.token)
         } else {
             None
         }
     }
 
-    pub(crate) fn store(
-        &self,
-        credentials: AppCredentials,
-        token: String,
-        expires_in_seconds: Option<i64>,
-    ) {
+    /// Evicts the cached token for `key` if it is still `stale_token`.
+    pub(crate) fn invalidate(&self, key: &str, stale_token: &str) {
+        if self
+            .store
+            .get(key)
+            .is_some_and(|cached| cached.token == stale_token)
+        {
+            self.store.invalidate(key);
+        }
+    }
+
+    pub(crate) fn store(&self, key: &str, token: String, expires_in_seconds: Option<i64>) {
         let ttl = normalize_token_ttl(expires_in_seconds);
-        let expires_at = Instant::now().checked_add(ttl).unwrap_or_else(Instant::now);
+        let now = SystemTime::now();
+        let expires_at = now.checked_add(ttl).unwrap_or(now);
+        self.store
+            .store(key, CachedAccessToken::new(token, expires_at));
+    }
+}
+
+/// Cell holding the outcome of one access-token refresh.
+pub(crate) trait RefreshCell: Default {
+    /// Returns `true` once the refresh has produced a result.
+    fn is_settled(&self) -> bool;
+}
+
+#[cfg(feature = "_async")]
+impl RefreshCell for tokio::sync::OnceCell<Result<String>> {
+    fn is_settled(&self) -> bool {
+        self.initialized()
+    }
+}
+
+#[cfg(feature = "_blocking")]
+impl RefreshCell for std::sync::OnceLock<Result<String>> {
+    fn is_settled(&self) -> bool {
+        self.get().is_some()
+    }
+}
+
+/// In-flight access-token refreshes per cache key, shared by every concurrent caller.
+#[derive(Debug)]
+pub(crate) struct TokenRefreshes<C> {
+    slots: Mutex<HashMap<String, Arc<C>>>,
+}
 
-        if let Ok(mut guard) = self.inner.write() {
-            guard.insert(credentials, CachedAccessToken { token, expires_at });
+impl<C> Default for TokenRefreshes<C> {
+    fn default() -> Self {
+        Self {
+            slots: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<C: RefreshCell> TokenRefreshes<C> {
+    /// Joins the pending refresh for `key`, or starts a new one if none is pending.
+    pub(crate) fn join(&self, key: &str) -> RefreshSlot<'_, C> {
+        let mut slots = self.slots.lock().unwrap_or_else(PoisonError::into_inner);
+        let cell = Arc::clone(slots.entry(key.to_string()).or_default());
+        RefreshSlot {
+            refreshes: self,
+            key: key.to_string(),
+            cell,
+        }
+    }
+}
+
+/// Handle on a pending refresh; the entry is dropped once the refresh settles or is abandoned.
+pub(crate) struct RefreshSlot<'a, C: RefreshCell> {
+    refreshes: &'a TokenRefreshes<C>,
+    key: String,
+    cell: Arc<C>,
+}
+
+impl<C: RefreshCell> std::ops::Deref for RefreshSlot<'_, C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        &self.cell
+    }
+}
+
+impl<C: RefreshCell> Drop for RefreshSlot<'_, C> {
+    fn drop(&mut self) {
+        let mut slots = self
+            .refreshes
+            .slots
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        // Handles are only cloned under this lock, so a count of two means the map and
+        // this handle are the last owners of an abandoned refresh.
+        if slots
+            .get(&self.key)
+            .is_some_and(|cell| Arc::ptr_eq(cell, &self.cell))
+            && (self.cell.is_settled() || Arc::strong_count(&self.cell) == 2)
+        {
+            slots.remove(&self.key);
         }
     }
 }
@@ -174,17 +325,50 @@ pub(crate) fn validate_standard_api_response(
     validate_standard_api_response_with_request_id(body, None, body_snippet)
 }
 
-pub(crate) fn parse_standard_api_text_response(
+pub(crate) fn parse_standard_api_response<T>(
     response: reqx::Response,
     body_snippet: BodySnippetConfig,
-) -> Result<String> {
-    let response = successful_response_body(response, body_snippet)?;
-    validate_standard_api_response_with_request_id(
-        &response.body,
-        response.header_request_id,
-        body_snippet,
-    )?;
-    Ok(response.body)
+) -> Result<T>
+where
+    T: RawBodyResponse,
+{
+    let SuccessfulResponseBody {
+        body,
+        header_request_id,
+    } = successful_response_body(response, body_snippet)?;
+    validate_standard_api_response_with_request_id(&body, header_request_id, body_snippet)?;
+    // The message was already accepted; a non-JSON body must not turn into a retryable error.
+    let mut value: T = match serde_json::from_str::<serde_json::Value>(&body) {
+        Ok(json) => serde_json::from_value(json)?,
+        Err(_) => T::default(),
+    };
+    value.set_raw_body(body);
+    Ok(value)
+}
+
+pub(crate) fn parse_standard_json_response<T>(
+    response: reqx::Response,
+    body_snippet: BodySnippetConfig,
+) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let SuccessfulResponseBody {
+        body,
+        header_request_id,
+    } = successful_response_body(response, body_snippet)?;
+    validate_standard_api_response_with_request_id(&body, header_request_id, body_snippet)?;
+    Ok(serde_json::from_str(&body)?)
+}
+
+pub(crate) fn parse_json_response<T>(
+    response: reqx::Response,
+    body_snippet: BodySnippetConfig,
+) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    Ok(decode_json_response(response, body_snippet)?.value)
 }
 
 pub(crate) fn parse_get_token_response(
@@ -536,6 +720,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_session_webhook_url_rejects_expired_session() {
+        let url = "https://oapi.dingtalk.com/robot/sendBySession?session=abc";
+        let error = build_session_webhook_url(url, Some(1)).expect_err("session should be expired");
+        assert!(matches!(
+            error,
+            Error::SessionExpired {
+                expired_at_millis: 1
+            }
+        ));
+
+        let url = build_session_webhook_url(url, Some(i64::MAX)).expect("session should be valid");
+        assert_eq!(url.query(), Some("session=abc"));
+    }
+
+    #[test]
+    fn build_oauth_authorize_url_encodes_login_parameters() {
+        let request = OAuthAuthorizeRequest::new("https://portal.example.com/callback?from=dt")
+            .state("csrf-1")
+            .corp_id("ding-corp");
+        let url = build_oauth_authorize_url("app-key", &request).expect("url");
+        assert_eq!(
+            url.as_str(),
+            "https://login.dingtalk.com/oauth2/auth?redirect_uri=https%3A%2F%2Fportal.example.com%2Fcallback%3Ffrom%3Ddt&response_type=code&client_id=app-key&scope=openid&state=csrf-1&prompt=consent&corpId=ding-corp"
+        );
+    }
+
     #[test]
     fn api_error_response_is_detected() {
         let body = r#"{"errcode":310000,"errmsg":"invalid"}"#;
@@ -589,16 +800,43 @@ mod tests {
     }
 
     #[test]
-    fn access_token_cache_honors_refresh_margin_per_credentials() {
-        let cache = AccessTokenCache::new();
-        let credentials = AppCredentials::new("app-key", "app-secret");
-        cache.store(credentials.clone(), "token".to_string(), Some(1));
-        assert!(cache.get(&credentials, Duration::from_secs(60)).is_none());
+    fn invalid_token_errors_are_detected() {
+        assert!(is_invalid_token_error(&api_error(
+            42001,
+            "access_token expired",
+            None,
+            None
+        )));
+        assert!(!is_invalid_token_error(&api_error(
+            60011,
+            "no permission",
+            None,
+            None
+        )));
+        assert!(is_invalid_token_error(&Error::Auth(HttpError {
+            status: 401,
+            message: None,
+            request_id: None,
+            body_snippet: None,
+        })));
+    }
+
+    #[test]
+    fn access_token_cache_honors_refresh_margin_per_appkey() {
+        let store = Arc::new(InMemoryAccessTokenStore::new());
+        let cache = AccessTokenCache::new(Arc::clone(&store));
+        cache.store("app-key", "token".to_string(), Some(1));
+        assert!(cache.get("app-key", Duration::from_secs(60)).is_none());
 
-        cache.store(credentials.clone(), "token".to_string(), Some(60));
+        cache.store("app-key", "token".to_string(), Some(60));
+        assert_eq!(
+            cache.get("app-key", Duration::from_secs(0)).as_deref(),
+            Some("token")
+        );
         assert_eq!(
-            cache.get(&credentials, Duration::from_secs(0)).as_deref(),
+            store.get("app-key").map(|cached| cached.token).as_deref(),
             Some("token")
         );
+        assert!(store.get("other-key").is_none());
     }
 }
@@ -0,0 +1,385 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// User mentioned in a robot callback message.
+pub struct RobotAtUser {
+    /// DingTalk id of the mentioned user.
+    #[serde(default, rename = "dingtalkId")]
+    pub dingtalk_id: Option<String>,
+    /// Staff id of the mentioned user when within the robot's organization.
+    #[serde(default, rename = "staffId")]
+    pub staff_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// `text` callback content.
+pub struct RobotTextContent {
+    /// Text content, including the leading robot mention stripped by DingTalk.
+    #[serde(default)]
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Single segment of a `richText` callback content.
+pub struct RobotRichTextItem {
+    /// Text segment.
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Segment type (for example `picture`).
+    #[serde(default, rename = "type")]
+    pub item_type: Option<String>,
+    /// Download code for media segments.
+    #[serde(default, rename = "downloadCode")]
+    pub download_code: Option<String>,
+    /// Picture download code for picture segments.
+    #[serde(default, rename = "pictureDownloadCode")]
+    pub picture_download_code: Option<String>,
+    /// Additional fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// `richText` callback content.
+pub struct RobotRichTextContent {
+    /// Ordered rich-text segments.
+    #[serde(default, rename = "richText")]
+    pub rich_text: Vec<RobotRichTextItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// `picture` callback content.
+pub struct RobotPictureContent {
+    /// Download code for the picture.
+    #[serde(default, rename = "downloadCode")]
+    pub download_code: Option<String>,
+    /// Picture download code.
+    #[serde(default, rename = "pictureDownloadCode")]
+    pub picture_download_code: Option<String>,
+    /// Additional fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// `audio` callback content.
+pub struct RobotAudioContent {
+    /// Audio duration in milliseconds.
+    #[serde(default)]
+    pub duration: Option<i64>,
+    /// Download code for the audio file.
+    #[serde(default, rename = "downloadCode")]
+    pub download_code: Option<String>,
+    /// Speech recognition result.
+    #[serde(default)]
+    pub recognition: Option<String>,
+    /// Additional fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// `file` callback content.
+pub struct RobotFileContent {
+    /// Download code for the file.
+    #[serde(default, rename = "downloadCode")]
+    pub download_code: Option<String>,
+    /// File name.
+    #[serde(default, rename = "fileName")]
+    pub file_name: Option<String>,
+    /// File id.
+    #[serde(default, rename = "fileId")]
+    pub file_id: Option<String>,
+    /// DingTalk drive space id.
+    #[serde(default, rename = "spaceId")]
+    pub space_id: Option<String>,
+    /// Additional fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+/// Typed content of a robot callback message, selected by `msgtype`.
+pub enum RobotMessageContent {
+    /// `text` message.
+    Text(RobotTextContent),
+    /// `richText` message.
+    RichText(RobotRichTextContent),
+    /// `picture` message.
+    Picture(RobotPictureContent),
+    /// `audio` message.
+    Audio(RobotAudioContent),
+    /// `file` message.
+    File(RobotFileContent),
+    /// Message type not modeled explicitly.
+    Unknown {
+        /// Raw `msgtype` value.
+        msgtype: String,
+        /// Raw content payload.
+        content: Value,
+    },
+}
+
+/// Incoming robot callback message (HTTP callback or Stream Mode payload).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "RawRobotCallbackMessage")]
+#[non_exhaustive]
+pub struct RobotCallbackMessage {
+    /// Message id.
+    pub msg_id: Option<String>,
+    /// Raw `msgtype` value.
+    pub msgtype: String,
+    /// Typed message content.
+    pub content: RobotMessageContent,
+    /// Conversation id (`openConversationId` for group chats).
+    pub conversation_id: Option<String>,
+    /// Conversation type (`1` private, `2` group).
+    pub conversation_type: Option<String>,
+    /// Group conversation title.
+    pub conversation_title: Option<String>,
+    /// Encrypted sender id.
+    pub sender_id: Option<String>,
+    /// Sender nickname.
+    pub sender_nick: Option<String>,
+    /// Sender staff id when the sender belongs to the robot's organization.
+    pub sender_staff_id: Option<String>,
+    /// Sender corp id.
+    pub sender_corp_id: Option<String>,
+    /// Whether the sender is an organization admin.
+    pub is_admin: Option<bool>,
+    /// Whether the robot was mentioned.
+    pub is_in_at_list: Option<bool>,
+    /// Users mentioned in the message.
+    pub at_users: Vec<RobotAtUser>,
+    /// Corp id of the robot.
+    pub chatbot_corp_id: Option<String>,
+    /// User id of the robot.
+    pub chatbot_user_id: Option<String>,
+    /// Robot code.
+    pub robot_code: Option<String>,
+    /// Short-lived webhook URL for replying to this conversation.
+    pub session_webhook: Option<String>,
+    /// Expiry of `session_webhook` as a millisecond Unix timestamp.
+    pub session_webhook_expired_time: Option<i64>,
+    /// Message creation time as a millisecond Unix timestamp.
+    pub create_at: Option<i64>,
+    /// Additional fields not modeled explicitly.
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl RobotCallbackMessage {
+    /// Returns `true` for one-to-one conversations.
+    #[must_use]
+    pub fn is_private(&self) -> bool {
+        self.conversation_type.as_deref() == Some("1")
+    }
+
+    /// Returns the text content for `text` messages.
+    #[must_use]
+    pub fn text(&self) -> Option<&str> {
+        match &self.content {
+            RobotMessageContent::Text(text) => Some(&text.content),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawRobotCallbackMessage {
+    #[serde(default)]
+    msg_id: Option<String>,
+    #[serde(default)]
+    msgtype: String,
+    #[serde(default)]
+    text: Option<Value>,
+    #[serde(default)]
+    content: Option<Value>,
+    #[serde(default)]
+    conversation_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_string_or_number")]
+    conversation_type: Option<String>,
+    #[serde(default)]
+    conversation_title: Option<String>,
+    #[serde(default)]
+    sender_id: Option<String>,
+    #[serde(default)]
+    sender_nick: Option<String>,
+    #[serde(default)]
+    sender_staff_id: Option<String>,
+    #[serde(default)]
+    sender_corp_id: Option<String>,
+    #[serde(default)]
+    is_admin: Option<bool>,
+    #[serde(default)]
+    is_in_at_list: Option<bool>,
+    #[serde(default)]
+    at_users: Vec<RobotAtUser>,
+    #[serde(default)]
+    chatbot_corp_id: Option<String>,
+    #[serde(default)]
+    chatbot_user_id: Option<String>,
+    #[serde(default)]
+    robot_code: Option<String>,
+    #[serde(default)]
+    session_webhook: Option<String>,
+    #[serde(default)]
+    session_webhook_expired_time: Option<i64>,
+    #[serde(default)]
+    create_at: Option<i64>,
+    #[serde(flatten, default)]
+    extra: BTreeMap<String, Value>,
+}
+
+impl TryFrom<RawRobotCallbackMessage> for RobotCallbackMessage {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawRobotCallbackMessage) -> Result<Self, Self::Error> {
+        let content = match raw.msgtype.as_str() {
+            "text" => RobotMessageContent::Text(serde_json::from_value(content_value(
+                raw.text.or(raw.content),
+            ))?),
+            "richText" => {
+                RobotMessageContent::RichText(serde_json::from_value(content_value(raw.content))?)
+            }
+            "picture" => {
+                RobotMessageContent::Picture(serde_json::from_value(content_value(raw.content))?)
+            }
+            "audio" => {
+                RobotMessageContent::Audio(serde_json::from_value(content_value(raw.content))?)
+            }
+            "file" => {
+                RobotMessageContent::File(serde_json::from_value(content_value(raw.content))?)
+            }
+            other => RobotMessageContent::Unknown {
+                msgtype: other.to_string(),
+                content: raw.content.or(raw.text).unwrap_or(Value::Null),
+            },
+        };
+
+        Ok(Self {
+            msg_id: raw.msg_id,
+            msgtype: raw.msgtype,
+            content,
+            conversation_id: raw.conversation_id,
+            conversation_type: raw.conversation_type,
+            conversation_title: raw.conversation_title,
+            sender_id: raw.sender_id,
+            sender_nick: raw.sender_nick,
+            sender_staff_id: raw.sender_staff_id,
+            sender_corp_id: raw.sender_corp_id,
+            is_admin: raw.is_admin,
+            is_in_at_list: raw.is_in_at_list,
+            at_users: raw.at_users,
+            chatbot_corp_id: raw.chatbot_corp_id,
+            chatbot_user_id: raw.chatbot_user_id,
+            robot_code: raw.robot_code,
+            session_webhook: raw.session_webhook,
+            session_webhook_expired_time: raw.session_webhook_expired_time,
+            create_at: raw.create_at,
+            extra: raw.extra,
+        })
+    }
+}
+
+fn content_value(content: Option<Value>) -> Value {
+    content.unwrap_or_else(|| Value::Object(serde_json::Map::new()))
+}
+
+fn deserialize_optional_string_or_number<'de, D>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::String(value)) => Some(value),
+        Some(Value::Number(value)) => Some(value.to_string()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RobotCallbackMessage, RobotMessageContent};
+
+    #[test]
+    fn robot_callback_message_parses_text_payload() {
+        let raw = r#"{
+            "conversationId": "cid-1",
+            "atUsers": [{"dingtalkId": "ding-1", "staffId": "staff-1"}],
+            "chatbotCorpId": "corp-1",
+            "msgId": "msg-1",
+            "senderNick": "Alice",
+            "isAdmin": true,
+            "senderStaffId": "manager-1",
+            "sessionWebhookExpiredTime": 1700000000000,
+            "conversationType": 1,
+            "sessionWebhook": "https://oapi.dingtalk.com/robot/sendBySession?session=abc",
+            "text": {"content": " hello"},
+            "msgtype": "text",
+            "unknownField": "kept"
+        }"#;
+        let parsed: RobotCallbackMessage =
+            serde_json::from_str(raw).expect("callback should deserialize");
+
+        assert!(parsed.is_private());
+        assert_eq!(parsed.text(), Some(" hello"));
+        assert_eq!(parsed.sender_staff_id.as_deref(), Some("manager-1"));
+        assert_eq!(parsed.at_users[0].staff_id.as_deref(), Some("staff-1"));
+        assert_eq!(parsed.session_webhook_expired_time, Some(1_700_000_000_000));
+        assert_eq!(
+            parsed
+                .extra
+                .get("unknownField")
+                .and_then(serde_json::Value::as_str),
+            Some("kept")
+        );
+    }
+
+    #[test]
+    fn robot_callback_message_parses_rich_text_and_unknown_payloads() {
+        let raw = r#"{
+            "conversationType": "2",
+            "msgtype": "richText",
+            "content": {"richText": [{"text": "see"}, {"type": "picture", "downloadCode": "dl-1"}]}
+        }"#;
+        let parsed: RobotCallbackMessage =
+            serde_json::from_str(raw).expect("callback should deserialize");
+        assert!(!parsed.is_private());
+        match parsed.content {
+            RobotMessageContent::RichText(content) => {
+                assert_eq!(content.rich_text.len(), 2);
+                assert_eq!(content.rich_text[1].download_code.as_deref(), Some("dl-1"));
+            }
+            other => panic!("unexpected content: {other:?}"),
+        }
+
+        let raw = r#"{"msgtype": "text", "msgId": "msg-2"}"#;
+        let parsed: RobotCallbackMessage =
+            serde_json::from_str(raw).expect("text callback without content should deserialize");
+        assert_eq!(parsed.text(), Some(""));
+
+        let raw = r#"{"msgtype": "video", "content": {"videoType": "mp4"}}"#;
+        let parsed: RobotCallbackMessage =
+            serde_json::from_str(raw).expect("callback should deserialize");
+        match parsed.content {
+            RobotMessageContent::Unknown { msgtype, content } => {
+                assert_eq!(msgtype, "video");
+                assert_eq!(content["videoType"], "mp4");
+            }
+            other => panic!("unexpected content: {other:?}"),
+        }
+    }
+}
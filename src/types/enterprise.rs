@@ -1,8 +1,11 @@
 use std::collections::BTreeMap;
+use std::fmt::Display;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use serde_json::Value;
 
+use crate::types::internal::RawBodyResponse;
+
 /// Request for getting a user by `userid`.
 #[derive(Debug, Clone, Serialize)]
 pub struct ContactGetUserRequest {
@@ -121,6 +124,109 @@ impl ContactListUsersRequest {
     }
 }
 
+/// Request for listing user ids and names in a department.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactListSimpleUsersRequest {
+    /// Department id.
+    pub dept_id: i64,
+    /// Cursor for pagination.
+    pub cursor: i64,
+    /// Page size.
+    pub size: i64,
+    /// Language code (for example `zh_CN`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Optional ordering field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_field: Option<String>,
+    /// Whether to include access-limited users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contain_access_limit: Option<bool>,
+}
+
+impl ContactListSimpleUsersRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(dept_id: i64, cursor: i64, size: i64) -> Self {
+        Self {
+            dept_id,
+            cursor,
+            size,
+            language: None,
+            order_field: None,
+            contain_access_limit: None,
+        }
+    }
+
+    /// Sets language preference.
+    #[must_use]
+    pub fn language(mut self, value: impl Into<String>) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    /// Sets ordering field.
+    #[must_use]
+    pub fn order_field(mut self, value: impl Into<String>) -> Self {
+        self.order_field = Some(value.into());
+        self
+    }
+
+    /// Sets access-limit inclusion behavior.
+    #[must_use]
+    pub fn contain_access_limit(mut self, value: bool) -> Self {
+        self.contain_access_limit = Some(value);
+        self
+    }
+}
+
+/// Request for listing the user ids of a department.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactListUserIdsRequest {
+    /// Department id.
+    pub dept_id: i64,
+}
+
+impl ContactListUserIdsRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(dept_id: i64) -> Self {
+        Self { dept_id }
+    }
+}
+
+/// Request for counting the users of the organization.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactCountUsersRequest {
+    /// Whether to count only users who have activated DingTalk.
+    pub only_active: bool,
+}
+
+impl ContactCountUsersRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(only_active: bool) -> Self {
+        Self { only_active }
+    }
+}
+
+/// Request for getting the departments a sub-administrator manages.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactGetAdminScopeRequest {
+    /// Administrator user id.
+    pub userid: String,
+}
+
+impl ContactGetAdminScopeRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(userid: impl Into<String>) -> Self {
+        Self {
+            userid: userid.into(),
+        }
+    }
+}
+
 /// Request for creating a user.
 #[derive(Debug, Clone, Serialize)]
 pub struct ContactCreateUserRequest {
@@ -128,11 +234,54 @@ pub struct ContactCreateUserRequest {
     pub name: String,
     /// Mobile phone number.
     pub mobile: String,
-    /// Department id list encoded as DingTalk expects.
-    pub dept_id_list: String,
+    /// Departments the user joins.
+    #[serde(serialize_with = "serialize_comma_separated")]
+    pub dept_id_list: Vec<i64>,
     /// Optional user id.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub userid: Option<String>,
+    /// Job title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Personal email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Enterprise email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub org_email: Option<String>,
+    /// Employee number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_number: Option<String>,
+    /// Landline extension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub telephone: Option<String>,
+    /// Office location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub work_place: Option<String>,
+    /// Remark.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remark: Option<String>,
+    /// Hire date in milliseconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hired_date: Option<i64>,
+    /// Direct manager user id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manager_userid: Option<String>,
+    /// Whether the mobile number is hidden.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_mobile: Option<bool>,
+    /// Whether senior mode is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub senior_mode: Option<bool>,
+    /// Custom profile fields as a JSON string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension: Option<String>,
+    /// Per-department job titles.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dept_title_list: Vec<ContactDeptTitle>,
+    /// Per-department sort orders.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dept_order_list: Vec<ContactDeptOrder>,
     /// Additional pass-through fields supported by DingTalk.
     #[serde(flatten, skip_serializing_if = "BTreeMap::is_empty")]
     pub extra: BTreeMap<String, Value>,
@@ -144,13 +293,27 @@ impl ContactCreateUserRequest {
     pub fn new(
         name: impl Into<String>,
         mobile: impl Into<String>,
-        dept_id_list: impl Into<String>,
+        dept_id_list: impl IntoIterator<Item = i64>,
     ) -> Self {
         Self {
             name: name.into(),
             mobile: mobile.into(),
-            dept_id_list: dept_id_list.into(),
+            dept_id_list: dept_id_list.into_iter().collect(),
             userid: None,
+            title: None,
+            email: None,
+            org_email: None,
+            job_number: None,
+            telephone: None,
+            work_place: None,
+            remark: None,
+            hired_date: None,
+            manager_userid: None,
+            hide_mobile: None,
+            senior_mode: None,
+            extension: None,
+            dept_title_list: Vec::new(),
+            dept_order_list: Vec::new(),
             extra: BTreeMap::new(),
         }
     }
@@ -162,108 +325,104 @@ impl ContactCreateUserRequest {
         self
     }
 
-    /// Adds a custom extra field.
+    /// Sets job title.
     #[must_use]
-    pub fn insert_extra(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
-        self.extra.insert(key.into(), value.into());
+    pub fn title(mut self, value: impl Into<String>) -> Self {
+        self.title = Some(value.into());
         self
     }
-}
 
-/// Request for updating a user.
-#[derive(Debug, Clone, Serialize)]
-pub struct ContactUpdateUserRequest {
-    /// User id.
-    pub userid: String,
-    /// Additional pass-through fields supported by DingTalk.
-    #[serde(flatten, skip_serializing_if = "BTreeMap::is_empty")]
-    pub extra: BTreeMap<String, Value>,
-}
+    /// Sets personal email.
+    #[must_use]
+    pub fn email(mut self, value: impl Into<String>) -> Self {
+        self.email = Some(value.into());
+        self
+    }
 
-impl ContactUpdateUserRequest {
-    /// Creates a request with required fields.
+    /// Sets enterprise email.
     #[must_use]
-    pub fn new(userid: impl Into<String>) -> Self {
-        Self {
-            userid: userid.into(),
-            extra: BTreeMap::new(),
-        }
+    pub fn org_email(mut self, value: impl Into<String>) -> Self {
+        self.org_email = Some(value.into());
+        self
     }
 
-    /// Adds a custom extra field.
+    /// Sets employee number.
     #[must_use]
-    pub fn insert_extra(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
-        self.extra.insert(key.into(), value.into());
+    pub fn job_number(mut self, value: impl Into<String>) -> Self {
+        self.job_number = Some(value.into());
         self
     }
-}
 
-/// Request for deleting a user.
-#[derive(Debug, Clone, Serialize)]
-pub struct ContactDeleteUserRequest {
-    /// User id.
-    pub userid: String,
-}
+    /// Sets landline extension.
+    #[must_use]
+    pub fn telephone(mut self, value: impl Into<String>) -> Self {
+        self.telephone = Some(value.into());
+        self
+    }
 
-impl ContactDeleteUserRequest {
-    /// Creates a request with required fields.
+    /// Sets office location.
     #[must_use]
-    pub fn new(userid: impl Into<String>) -> Self {
-        Self {
-            userid: userid.into(),
-        }
+    pub fn work_place(mut self, value: impl Into<String>) -> Self {
+        self.work_place = Some(value.into());
+        self
     }
-}
 
-/// Request for getting a department.
-#[derive(Debug, Clone, Serialize)]
-pub struct ContactGetDepartmentRequest {
-    /// Department id.
-    pub dept_id: i64,
-    /// Language code (for example `zh_CN`).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
-}
+    /// Sets remark.
+    #[must_use]
+    pub fn remark(mut self, value: impl Into<String>) -> Self {
+        self.remark = Some(value.into());
+        self
+    }
 
-impl ContactGetDepartmentRequest {
-    /// Creates a request with required fields.
+    /// Sets hire date in milliseconds since the Unix epoch.
     #[must_use]
-    pub fn new(dept_id: i64) -> Self {
-        Self {
-            dept_id,
-            language: None,
-        }
+    pub fn hired_date(mut self, value: i64) -> Self {
+        self.hired_date = Some(value);
+        self
     }
 
-    /// Sets language preference.
+    /// Sets direct manager user id.
     #[must_use]
-    pub fn language(mut self, value: impl Into<String>) -> Self {
-        self.language = Some(value.into());
+    pub fn manager_userid(mut self, value: impl Into<String>) -> Self {
+        self.manager_userid = Some(value.into());
         self
     }
-}
 
-/// Request for creating a department.
-#[derive(Debug, Clone, Serialize)]
-pub struct ContactCreateDepartmentRequest {
-    /// Department name.
-    pub name: String,
-    /// Parent department id.
-    pub parent_id: i64,
-    /// Additional pass-through fields supported by DingTalk.
-    #[serde(flatten, skip_serializing_if = "BTreeMap::is_empty")]
-    pub extra: BTreeMap<String, Value>,
-}
+    /// Sets whether the mobile number is hidden.
+    #[must_use]
+    pub fn hide_mobile(mut self, value: bool) -> Self {
+        self.hide_mobile = Some(value);
+        self
+    }
 
-impl ContactCreateDepartmentRequest {
-    /// Creates a request with required fields.
+    /// Sets whether senior mode is enabled.
     #[must_use]
-    pub fn new(name: impl Into<String>, parent_id: i64) -> Self {
-        Self {
-            name: name.into(),
-            parent_id,
-            extra: BTreeMap::new(),
-        }
+    pub fn senior_mode(mut self, value: bool) -> Self {
+        self.senior_mode = Some(value);
+        self
+    }
+
+    /// Sets custom profile fields as a JSON string.
+    #[must_use]
+    pub fn extension(mut self, value: impl Into<String>) -> Self {
+        self.extension = Some(value.into());
+        self
+    }
+
+    /// Adds a job title for one department.
+    #[must_use]
+    pub fn dept_title(mut self, dept_id: i64, title: impl Into<String>) -> Self {
+        self.dept_title_list
+            .push(ContactDeptTitle::new(dept_id, title));
+        self
+    }
+
+    /// Adds a sort order for one department.
+    #[must_use]
+    pub fn dept_order(mut self, dept_id: i64, order: i64) -> Self {
+        self.dept_order_list
+            .push(ContactDeptOrder::new(dept_id, order));
+        self
     }
 
     /// Adds a custom extra field.
@@ -274,124 +433,1286 @@ impl ContactCreateDepartmentRequest {
     }
 }
 
-/// Request for updating a department.
+/// Request for updating a user.
 #[derive(Debug, Clone, Serialize)]
-pub struct ContactUpdateDepartmentRequest {
-    /// Department id.
-    pub dept_id: i64,
-    /// New department name.
+pub struct ContactUpdateUserRequest {
+    /// User id.
+    pub userid: String,
+    /// New display name.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    /// New parent department id.
+    /// New mobile phone number.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parent_id: Option<i64>,
+    pub mobile: Option<String>,
+    /// New department list.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub dept_id_list: Vec<i64>,
+    /// Job title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Personal email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Enterprise email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub org_email: Option<String>,
+    /// Employee number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_number: Option<String>,
+    /// Landline extension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub telephone: Option<String>,
+    /// Office location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub work_place: Option<String>,
+    /// Remark.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remark: Option<String>,
+    /// Hire date in milliseconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hired_date: Option<i64>,
+    /// Direct manager user id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manager_userid: Option<String>,
+    /// Whether the mobile number is hidden.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_mobile: Option<bool>,
+    /// Whether senior mode is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub senior_mode: Option<bool>,
+    /// Custom profile fields as a JSON string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension: Option<String>,
+    /// Per-department job titles.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dept_title_list: Vec<ContactDeptTitle>,
+    /// Per-department sort orders.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dept_order_list: Vec<ContactDeptOrder>,
+    /// Language code (for example `zh_CN`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
     /// Additional pass-through fields supported by DingTalk.
     #[serde(flatten, skip_serializing_if = "BTreeMap::is_empty")]
     pub extra: BTreeMap<String, Value>,
 }
 
-impl ContactUpdateDepartmentRequest {
+impl ContactUpdateUserRequest {
     /// Creates a request with required fields.
     #[must_use]
-    pub fn new(dept_id: i64) -> Self {
+    pub fn new(userid: impl Into<String>) -> Self {
         Self {
-            dept_id,
+            userid: userid.into(),
             name: None,
-            parent_id: None,
+            mobile: None,
+            dept_id_list: Vec::new(),
+            title: None,
+            email: None,
+            org_email: None,
+            job_number: None,
+            telephone: None,
+            work_place: None,
+            remark: None,
+            hired_date: None,
+            manager_userid: None,
+            hide_mobile: None,
+            senior_mode: None,
+            extension: None,
+            dept_title_list: Vec::new(),
+            dept_order_list: Vec::new(),
+            language: None,
             extra: BTreeMap::new(),
         }
     }
 
-    /// Sets department name.
+    /// Sets display name.
     #[must_use]
     pub fn name(mut self, value: impl Into<String>) -> Self {
         self.name = Some(value.into());
         self
     }
 
-    /// Sets parent department id.
+    /// Sets mobile phone number.
     #[must_use]
-    pub fn parent_id(mut self, value: i64) -> Self {
-        self.parent_id = Some(value);
+    pub fn mobile(mut self, value: impl Into<String>) -> Self {
+        self.mobile = Some(value.into());
         self
     }
 
-    /// Adds a custom extra field.
+    /// Replaces the department list.
     #[must_use]
-    pub fn insert_extra(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
-        self.extra.insert(key.into(), value.into());
+    pub fn dept_id_list(mut self, values: impl IntoIterator<Item = i64>) -> Self {
+        self.dept_id_list = values.into_iter().collect();
         self
     }
-}
 
-/// Request for deleting a department.
-#[derive(Debug, Clone, Serialize)]
+    /// Sets job title.
+    #[must_use]
+    pub fn title(mut self, value: impl Into<String>) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// Sets personal email.
+    #[must_use]
+    pub fn email(mut self, value: impl Into<String>) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets enterprise email.
+    #[must_use]
+    pub fn org_email(mut self, value: impl Into<String>) -> Self {
+        self.org_email = Some(value.into());
+        self
+    }
+
+    /// Sets employee number.
+    #[must_use]
+    pub fn job_number(mut self, value: impl Into<String>) -> Self {
+        self.job_number = Some(value.into());
+        self
+    }
+
+    /// Sets landline extension.
+    #[must_use]
+    pub fn telephone(mut self, value: impl Into<String>) -> Self {
+        self.telephone = Some(value.into());
+        self
+    }
+
+    /// Sets office location.
+    #[must_use]
+    pub fn work_place(mut self, value: impl Into<String>) -> Self {
+        self.work_place = Some(value.into());
+        self
+    }
+
+    /// Sets remark.
+    #[must_use]
+    pub fn remark(mut self, value: impl Into<String>) -> Self {
+        self.remark = Some(value.into());
+        self
+    }
+
+    /// Sets hire date in milliseconds since the Unix epoch.
+    #[must_use]
+    pub fn hired_date(mut self, value: i64) -> Self {
+        self.hired_date = Some(value);
+        self
+    }
+
+    /// Sets direct manager user id.
+    #[must_use]
+    pub fn manager_userid(mut self, value: impl Into<String>) -> Self {
+        self.manager_userid = Some(value.into());
+        self
+    }
+
+    /// Sets whether the mobile number is hidden.
+    #[must_use]
+    pub fn hide_mobile(mut self, value: bool) -> Self {
+        self.hide_mobile = Some(value);
+        self
+    }
+
+    /// Sets whether senior mode is enabled.
+    #[must_use]
+    pub fn senior_mode(mut self, value: bool) -> Self {
+        self.senior_mode = Some(value);
+        self
+    }
+
+    /// Sets custom profile fields as a JSON string.
+    #[must_use]
+    pub fn extension(mut self, value: impl Into<String>) -> Self {
+        self.extension = Some(value.into());
+        self
+    }
+
+    /// Adds a job title for one department.
+    #[must_use]
+    pub fn dept_title(mut self, dept_id: i64, title: impl Into<String>) -> Self {
+        self.dept_title_list
+            .push(ContactDeptTitle::new(dept_id, title));
+        self
+    }
+
+    /// Adds a sort order for one department.
+    #[must_use]
+    pub fn dept_order(mut self, dept_id: i64, order: i64) -> Self {
+        self.dept_order_list
+            .push(ContactDeptOrder::new(dept_id, order));
+        self
+    }
+
+    /// Sets language preference.
+    #[must_use]
+    pub fn language(mut self, value: impl Into<String>) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    /// Adds a custom extra field.
+    #[must_use]
+    pub fn insert_extra(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Request for deleting a user.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactDeleteUserRequest {
+    /// User id.
+    pub userid: String,
+}
+
+impl ContactDeleteUserRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(userid: impl Into<String>) -> Self {
+        Self {
+            userid: userid.into(),
+        }
+    }
+}
+
+/// Request for getting a department.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactGetDepartmentRequest {
+    /// Department id.
+    pub dept_id: i64,
+    /// Language code (for example `zh_CN`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+impl ContactGetDepartmentRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(dept_id: i64) -> Self {
+        Self {
+            dept_id,
+            language: None,
+        }
+    }
+
+    /// Sets language preference.
+    #[must_use]
+    pub fn language(mut self, value: impl Into<String>) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+}
+
+/// Request for listing the ancestor chain of a department.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactListParentDepartmentsByDeptRequest {
+    /// Department id.
+    pub dept_id: i64,
+}
+
+impl ContactListParentDepartmentsByDeptRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(dept_id: i64) -> Self {
+        Self { dept_id }
+    }
+}
+
+/// Request for listing the department chains of a user.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactListParentDepartmentsByUserRequest {
+    /// DingTalk user id.
+    pub userid: String,
+}
+
+impl ContactListParentDepartmentsByUserRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(userid: impl Into<String>) -> Self {
+        Self {
+            userid: userid.into(),
+        }
+    }
+}
+
+/// Request for creating a department.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactCreateDepartmentRequest {
+    /// Department name.
+    pub name: String,
+    /// Parent department id.
+    pub parent_id: i64,
+    /// Sort order among siblings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<i64>,
+    /// External source identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_identifier: Option<String>,
+    /// Department description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brief: Option<String>,
+    /// Whether to create a department group chat.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_dept_group: Option<bool>,
+    /// Whether new members join the group chat automatically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_add_user: Option<bool>,
+    /// Whether the department is hidden.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_dept: Option<bool>,
+    /// Departments allowed to see the hidden department.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub dept_permits: Vec<i64>,
+    /// Users allowed to see the hidden department.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub user_permits: Vec<String>,
+    /// Whether members only see permitted contacts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outer_dept: Option<bool>,
+    /// Departments visible to members of a restricted department.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub outer_permit_depts: Vec<i64>,
+    /// Users visible to members of a restricted department.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub outer_permit_users: Vec<String>,
+    /// Additional pass-through fields supported by DingTalk.
+    #[serde(flatten, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl ContactCreateDepartmentRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(name: impl Into<String>, parent_id: i64) -> Self {
+        Self {
+            name: name.into(),
+            parent_id,
+            order: None,
+            source_identifier: None,
+            brief: None,
+            create_dept_group: None,
+            auto_add_user: None,
+            hide_dept: None,
+            dept_permits: Vec::new(),
+            user_permits: Vec::new(),
+            outer_dept: None,
+            outer_permit_depts: Vec::new(),
+            outer_permit_users: Vec::new(),
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Sets sort order among siblings.
+    #[must_use]
+    pub fn order(mut self, value: i64) -> Self {
+        self.order = Some(value);
+        self
+    }
+
+    /// Sets external source identifier.
+    #[must_use]
+    pub fn source_identifier(mut self, value: impl Into<String>) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets department description.
+    #[must_use]
+    pub fn brief(mut self, value: impl Into<String>) -> Self {
+        self.brief = Some(value.into());
+        self
+    }
+
+    /// Sets whether to create a department group chat.
+    #[must_use]
+    pub fn create_dept_group(mut self, value: bool) -> Self {
+        self.create_dept_group = Some(value);
+        self
+    }
+
+    /// Sets whether new members join the group chat automatically.
+    #[must_use]
+    pub fn auto_add_user(mut self, value: bool) -> Self {
+        self.auto_add_user = Some(value);
+        self
+    }
+
+    /// Sets whether the department is hidden.
+    #[must_use]
+    pub fn hide_dept(mut self, value: bool) -> Self {
+        self.hide_dept = Some(value);
+        self
+    }
+
+    /// Sets departments allowed to see the hidden department.
+    #[must_use]
+    pub fn dept_permits(mut self, values: impl IntoIterator<Item = i64>) -> Self {
+        self.dept_permits = values.into_iter().collect();
+        self
+    }
+
+    /// Sets users allowed to see the hidden department.
+    #[must_use]
+    pub fn user_permits(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.user_permits = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets whether members only see permitted contacts.
+    #[must_use]
+    pub fn outer_dept(mut self, value: bool) -> Self {
+        self.outer_dept = Some(value);
+        self
+    }
+
+    /// Sets departments visible to members of a restricted department.
+    #[must_use]
+    pub fn outer_permit_depts(mut self, values: impl IntoIterator<Item = i64>) -> Self {
+        self.outer_permit_depts = values.into_iter().collect();
+        self
+    }
+
+    /// Sets users visible to members of a restricted department.
+    #[must_use]
+    pub fn outer_permit_users(
+        mut self,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.outer_permit_users = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds a custom extra field.
+    #[must_use]
+    pub fn insert_extra(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Request for updating a department.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactUpdateDepartmentRequest {
+    /// Department id.
+    pub dept_id: i64,
+    /// New department name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New parent department id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<i64>,
+    /// Sort order among siblings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<i64>,
+    /// External source identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_identifier: Option<String>,
+    /// Department description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brief: Option<String>,
+    /// Whether to create a department group chat.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_dept_group: Option<bool>,
+    /// Whether new members join the group chat automatically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_add_user: Option<bool>,
+    /// Whether the department is hidden.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_dept: Option<bool>,
+    /// Departments allowed to see the hidden department.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub dept_permits: Vec<i64>,
+    /// Users allowed to see the hidden department.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub user_permits: Vec<String>,
+    /// Whether members only see permitted contacts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outer_dept: Option<bool>,
+    /// Departments visible to members of a restricted department.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub outer_permit_depts: Vec<i64>,
+    /// Users visible to members of a restricted department.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub outer_permit_users: Vec<String>,
+    /// Department manager user ids.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub dept_manager_userid_list: Vec<String>,
+    /// Department owner user id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub org_dept_owner: Option<String>,
+    /// Whether the group chat includes sub-department members.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_contain_sub_dept: Option<bool>,
+    /// Language code (for example `zh_CN`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Additional pass-through fields supported by DingTalk.
+    #[serde(flatten, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl ContactUpdateDepartmentRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(dept_id: i64) -> Self {
+        Self {
+            dept_id,
+            name: None,
+            parent_id: None,
+            order: None,
+            source_identifier: None,
+            brief: None,
+            create_dept_group: None,
+            auto_add_user: None,
+            hide_dept: None,
+            dept_permits: Vec::new(),
+            user_permits: Vec::new(),
+            outer_dept: None,
+            outer_permit_depts: Vec::new(),
+            outer_permit_users: Vec::new(),
+            dept_manager_userid_list: Vec::new(),
+            org_dept_owner: None,
+            group_contain_sub_dept: None,
+            language: None,
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Sets department name.
+    #[must_use]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets parent department id.
+    #[must_use]
+    pub fn parent_id(mut self, value: i64) -> Self {
+        self.parent_id = Some(value);
+        self
+    }
+
+    /// Sets sort order among siblings.
+    #[must_use]
+    pub fn order(mut self, value: i64) -> Self {
+        self.order = Some(value);
+        self
+    }
+
+    /// Sets external source identifier.
+    #[must_use]
+    pub fn source_identifier(mut self, value: impl Into<String>) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets department description.
+    #[must_use]
+    pub fn brief(mut self, value: impl Into<String>) -> Self {
+        self.brief = Some(value.into());
+        self
+    }
+
+    /// Sets whether to create a department group chat.
+    #[must_use]
+    pub fn create_dept_group(mut self, value: bool) -> Self {
+        self.create_dept_group = Some(value);
+        self
+    }
+
+    /// Sets whether new members join the group chat automatically.
+    #[must_use]
+    pub fn auto_add_user(mut self, value: bool) -> Self {
+        self.auto_add_user = Some(value);
+        self
+    }
+
+    /// Sets whether the department is hidden.
+    #[must_use]
+    pub fn hide_dept(mut self, value: bool) -> Self {
+        self.hide_dept = Some(value);
+        self
+    }
+
+    /// Sets departments allowed to see the hidden department.
+    #[must_use]
+    pub fn dept_permits(mut self, values: impl IntoIterator<Item = i64>) -> Self {
+        self.dept_permits = values.into_iter().collect();
+        self
+    }
+
+    /// Sets users allowed to see the hidden department.
+    #[must_use]
+    pub fn user_permits(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.user_permits = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets whether members only see permitted contacts.
+    #[must_use]
+    pub fn outer_dept(mut self, value: bool) -> Self {
+        self.outer_dept = Some(value);
+        self
+    }
+
+    /// Sets departments visible to members of a restricted department.
+    #[must_use]
+    pub fn outer_permit_depts(mut self, values: impl IntoIterator<Item = i64>) -> Self {
+        self.outer_permit_depts = values.into_iter().collect();
+        self
+    }
+
+    /// Sets users visible to members of a restricted department.
+    #[must_use]
+    pub fn outer_permit_users(
+        mut self,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.outer_permit_users = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets department manager user ids.
+    #[must_use]
+    pub fn dept_manager_userid_list(
+        mut self,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.dept_manager_userid_list = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets department owner user id.
+    #[must_use]
+    pub fn org_dept_owner(mut self, value: impl Into<String>) -> Self {
+        self.org_dept_owner = Some(value.into());
+        self
+    }
+
+    /// Sets whether the group chat includes sub-department members.
+    #[must_use]
+    pub fn group_contain_sub_dept(mut self, value: bool) -> Self {
+        self.group_contain_sub_dept = Some(value);
+        self
+    }
+
+    /// Sets language preference.
+    #[must_use]
+    pub fn language(mut self, value: impl Into<String>) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    /// Adds a custom extra field.
+    #[must_use]
+    pub fn insert_extra(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Request for deleting a department.
+#[derive(Debug, Clone, Serialize)]
 pub struct ContactDeleteDepartmentRequest {
     /// Department id.
     pub dept_id: i64,
 }
 
-impl ContactDeleteDepartmentRequest {
+impl ContactDeleteDepartmentRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(dept_id: i64) -> Self {
+        Self { dept_id }
+    }
+}
+
+/// Request for listing child departments.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactListSubDepartmentsRequest {
+    /// Department id.
+    pub dept_id: i64,
+    /// Language code (for example `zh_CN`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+/// Request for listing child department ids.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactListSubDepartmentIdsRequest {
+    /// Department id.
+    pub dept_id: i64,
+    /// Language code (for example `zh_CN`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+impl ContactListSubDepartmentIdsRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(dept_id: i64) -> Self {
+        Self {
+            dept_id,
+            language: None,
+        }
+    }
+
+    /// Sets language preference.
+    #[must_use]
+    pub fn language(mut self, value: impl Into<String>) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+}
+
+impl ContactListSubDepartmentsRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(dept_id: i64) -> Self {
+        Self {
+            dept_id,
+            language: None,
+        }
+    }
+
+    /// Sets language preference.
+    #[must_use]
+    pub fn language(mut self, value: impl Into<String>) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+}
+
+/// Request for listing users who have not used DingTalk on a given day.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactListInactiveUsersRequest {
+    /// Whether to list active users instead of inactive ones.
+    pub is_active: bool,
+    /// Departments to query (all departments when empty).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dept_ids: Vec<i64>,
+    /// Offset of the first record.
+    pub offset: i64,
+    /// Page size (at most `100`).
+    pub size: i64,
+    /// Day to query, formatted as `yyyyMMdd`.
+    pub query_date: String,
+}
+
+impl ContactListInactiveUsersRequest {
+    /// Creates a request for inactive users on `query_date` (`yyyyMMdd`).
+    #[must_use]
+    pub fn new(query_date: impl Into<String>, offset: i64, size: i64) -> Self {
+        Self {
+            is_active: false,
+            dept_ids: Vec::new(),
+            offset,
+            size,
+            query_date: query_date.into(),
+        }
+    }
+
+    /// Lists active users instead of inactive ones.
+    #[must_use]
+    pub fn is_active(mut self, value: bool) -> Self {
+        self.is_active = value;
+        self
+    }
+
+    /// Restricts the query to departments.
+    #[must_use]
+    pub fn dept_ids(mut self, values: impl IntoIterator<Item = i64>) -> Self {
+        self.dept_ids = values.into_iter().collect();
+        self
+    }
+}
+
+/// Request for listing resigned employees within a time range.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactListEmpLeaveRecordsRequest {
+    /// Range start as an ISO-8601 timestamp (for example `2024-01-01T00:00:00Z`).
+    #[serde(rename = "startTime")]
+    pub start_time: String,
+    /// Range end as an ISO-8601 timestamp (defaults to now).
+    #[serde(rename = "endTime", skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
+    /// Token of the page to fetch.
+    #[serde(rename = "nextToken", skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// Page size (at most `50`).
+    #[serde(rename = "maxResults")]
+    pub max_results: i64,
+}
+
+impl ContactListEmpLeaveRecordsRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(start_time: impl Into<String>, max_results: i64) -> Self {
+        Self {
+            start_time: start_time.into(),
+            end_time: None,
+            next_token: None,
+            max_results,
+        }
+    }
+
+    /// Sets range end.
+    #[must_use]
+    pub fn end_time(mut self, value: impl Into<String>) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets page token.
+    #[must_use]
+    pub fn next_token(mut self, value: impl Into<String>) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+}
+
+/// Request for listing external contacts.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactListExternalContactsRequest {
+    /// Offset of the first record.
+    pub offset: i64,
+    /// Page size (at most `100`).
+    pub size: i64,
+}
+
+impl ContactListExternalContactsRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(offset: i64, size: i64) -> Self {
+        Self { offset, size }
+    }
+}
+
+/// Request for getting an external contact.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactGetExternalContactRequest {
+    /// External contact user id.
+    pub user_id: String,
+}
+
+impl ContactGetExternalContactRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(user_id: impl Into<String>) -> Self {
+        Self {
+            user_id: user_id.into(),
+        }
+    }
+}
+
+/// Request for creating an external contact.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactCreateExternalContactRequest {
+    /// Contact name.
+    pub name: String,
+    /// User id of the employee who follows up the contact.
+    pub follower_user_id: String,
+    /// Mobile phone number.
+    pub mobile: String,
+    /// Label ids (see [`ContactExternalLabelGroup`]).
+    pub label_ids: Vec<i64>,
+    /// Mobile country code (for example `86`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_code: Option<String>,
+    /// Company name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<String>,
+    /// Job title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// Remark.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remark: Option<String>,
+    /// Departments the contact is shared with.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub share_dept_ids: Vec<i64>,
+    /// Users the contact is shared with.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub share_user_ids: Vec<String>,
+    /// Additional pass-through fields supported by DingTalk.
+    #[serde(flatten, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl ContactCreateExternalContactRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        follower_user_id: impl Into<String>,
+        mobile: impl Into<String>,
+        label_ids: impl IntoIterator<Item = i64>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            follower_user_id: follower_user_id.into(),
+            mobile: mobile.into(),
+            label_ids: label_ids.into_iter().collect(),
+            state_code: None,
+            company_name: None,
+            title: None,
+            address: None,
+            remark: None,
+            share_dept_ids: Vec::new(),
+            share_user_ids: Vec::new(),
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Sets mobile country code.
+    #[must_use]
+    pub fn state_code(mut self, value: impl Into<String>) -> Self {
+        self.state_code = Some(value.into());
+        self
+    }
+
+    /// Sets company name.
+    #[must_use]
+    pub fn company_name(mut self, value: impl Into<String>) -> Self {
+        self.company_name = Some(value.into());
+        self
+    }
+
+    /// Sets job title.
+    #[must_use]
+    pub fn title(mut self, value: impl Into<String>) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// Sets address.
+    #[must_use]
+    pub fn address(mut self, value: impl Into<String>) -> Self {
+        self.address = Some(value.into());
+        self
+    }
+
+    /// Sets remark.
+    #[must_use]
+    pub fn remark(mut self, value: impl Into<String>) -> Self {
+        self.remark = Some(value.into());
+        self
+    }
+
+    /// Sets departments the contact is shared with.
+    #[must_use]
+    pub fn share_dept_ids(mut self, values: impl IntoIterator<Item = i64>) -> Self {
+        self.share_dept_ids = values.into_iter().collect();
+        self
+    }
+
+    /// Sets users the contact is shared with.
+    #[must_use]
+    pub fn share_user_ids(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.share_user_ids = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds a custom extra field.
+    #[must_use]
+    pub fn insert_extra(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Request for updating an external contact.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactUpdateExternalContactRequest {
+    /// External contact user id.
+    pub user_id: String,
+    /// Contact name.
+    pub name: String,
+    /// User id of the employee who follows up the contact.
+    pub follower_user_id: String,
+    /// Label ids (see [`ContactExternalLabelGroup`]).
+    pub label_ids: Vec<i64>,
+    /// Mobile phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<String>,
+    /// Mobile country code (for example `86`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_code: Option<String>,
+    /// Company name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<String>,
+    /// Job title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// Remark.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remark: Option<String>,
+    /// Departments the contact is shared with.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub share_dept_ids: Vec<i64>,
+    /// Users the contact is shared with.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub share_user_ids: Vec<String>,
+    /// Additional pass-through fields supported by DingTalk.
+    #[serde(flatten, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl ContactUpdateExternalContactRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(
+        user_id: impl Into<String>,
+        name: impl Into<String>,
+        follower_user_id: impl Into<String>,
+        label_ids: impl IntoIterator<Item = i64>,
+    ) -> Self {
+        Self {
+            user_id: user_id.into(),
+            name: name.into(),
+            follower_user_id: follower_user_id.into(),
+            label_ids: label_ids.into_iter().collect(),
+            mobile: None,
+            state_code: None,
+            company_name: None,
+            title: None,
+            address: None,
+            remark: None,
+            share_dept_ids: Vec::new(),
+            share_user_ids: Vec::new(),
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Sets mobile phone number.
+    #[must_use]
+    pub fn mobile(mut self, value: impl Into<String>) -> Self {
+        self.mobile = Some(value.into());
+        self
+    }
+
+    /// Sets mobile country code.
+    #[must_use]
+    pub fn state_code(mut self, value: impl Into<String>) -> Self {
+        self.state_code = Some(value.into());
+        self
+    }
+
+    /// Sets company name.
+    #[must_use]
+    pub fn company_name(mut self, value: impl Into<String>) -> Self {
+        self.company_name = Some(value.into());
+        self
+    }
+
+    /// Sets job title.
+    #[must_use]
+    pub fn title(mut self, value: impl Into<String>) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// Sets address.
+    #[must_use]
+    pub fn address(mut self, value: impl Into<String>) -> Self {
+        self.address = Some(value.into());
+        self
+    }
+
+    /// Sets remark.
+    #[must_use]
+    pub fn remark(mut self, value: impl Into<String>) -> Self {
+        self.remark = Some(value.into());
+        self
+    }
+
+    /// Sets departments the contact is shared with.
+    #[must_use]
+    pub fn share_dept_ids(mut self, values: impl IntoIterator<Item = i64>) -> Self {
+        self.share_dept_ids = values.into_iter().collect();
+        self
+    }
+
+    /// Sets users the contact is shared with.
+    #[must_use]
+    pub fn share_user_ids(mut self, values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.share_user_ids = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds a custom extra field.
+    #[must_use]
+    pub fn insert_extra(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Request for deleting an external contact.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactDeleteExternalContactRequest {
+    /// External contact user id.
+    pub user_id: String,
+}
+
+impl ContactDeleteExternalContactRequest {
     /// Creates a request with required fields.
     #[must_use]
-    pub fn new(dept_id: i64) -> Self {
-        Self { dept_id }
+    pub fn new(user_id: impl Into<String>) -> Self {
+        Self {
+            user_id: user_id.into(),
+        }
     }
 }
 
-/// Request for listing child departments.
+/// Request for listing external contact label groups.
 #[derive(Debug, Clone, Serialize)]
-pub struct ContactListSubDepartmentsRequest {
-    /// Department id.
-    pub dept_id: i64,
-    /// Language code (for example `zh_CN`).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
+pub struct ContactListExternalLabelGroupsRequest {
+    /// Offset of the first record.
+    pub offset: i64,
+    /// Page size (at most `100`).
+    pub size: i64,
 }
 
-/// Request for listing child department ids.
+impl ContactListExternalLabelGroupsRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(offset: i64, size: i64) -> Self {
+        Self { offset, size }
+    }
+}
+
+/// Request for listing role groups and their roles.
 #[derive(Debug, Clone, Serialize)]
-pub struct ContactListSubDepartmentIdsRequest {
-    /// Department id.
-    pub dept_id: i64,
-    /// Language code (for example `zh_CN`).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
+pub struct RoleListRequest {
+    /// Offset of the first record.
+    pub offset: i64,
+    /// Page size (at most `200`).
+    pub size: i64,
 }
 
-impl ContactListSubDepartmentIdsRequest {
+impl RoleListRequest {
     /// Creates a request with required fields.
     #[must_use]
-    pub fn new(dept_id: i64) -> Self {
-        Self {
-            dept_id,
-            language: None,
-        }
+    pub fn new(offset: i64, size: i64) -> Self {
+        Self { offset, size }
     }
+}
 
-    /// Sets language preference.
+/// Request for getting a role group.
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleGetRoleGroupRequest {
+    /// Role group id.
+    pub group_id: i64,
+}
+
+impl RoleGetRoleGroupRequest {
+    /// Creates a request with required fields.
     #[must_use]
-    pub fn language(mut self, value: impl Into<String>) -> Self {
-        self.language = Some(value.into());
-        self
+    pub fn new(group_id: i64) -> Self {
+        Self { group_id }
     }
 }
 
-impl ContactListSubDepartmentsRequest {
+/// Request for getting a role.
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleGetRoleRequest {
+    /// Role id.
+    #[serde(rename = "roleId")]
+    pub role_id: i64,
+}
+
+impl RoleGetRoleRequest {
     /// Creates a request with required fields.
     #[must_use]
-    pub fn new(dept_id: i64) -> Self {
+    pub fn new(role_id: i64) -> Self {
+        Self { role_id }
+    }
+}
+
+/// Request for listing the users that hold a role.
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleListUsersRequest {
+    /// Role id.
+    pub role_id: i64,
+    /// Offset of the first record.
+    pub offset: i64,
+    /// Page size (at most `200`).
+    pub size: i64,
+}
+
+impl RoleListUsersRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(role_id: i64, offset: i64, size: i64) -> Self {
         Self {
-            dept_id,
-            language: None,
+            role_id,
+            offset,
+            size,
         }
     }
+}
 
-    /// Sets language preference.
+/// Request for adding roles to, or removing roles from, users.
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleAssignmentRequest {
+    /// Role ids (at most `20`).
+    #[serde(rename = "roleIds", serialize_with = "serialize_comma_separated")]
+    pub role_ids: Vec<i64>,
+    /// User ids (at most `20`).
+    #[serde(rename = "userIds", serialize_with = "serialize_comma_separated")]
+    pub user_ids: Vec<String>,
+}
+
+impl RoleAssignmentRequest {
+    /// Creates a request with required fields.
     #[must_use]
-    pub fn language(mut self, value: impl Into<String>) -> Self {
-        self.language = Some(value.into());
-        self
+    pub fn new(
+        role_ids: impl IntoIterator<Item = i64>,
+        user_ids: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            role_ids: role_ids.into_iter().collect(),
+            user_ids: user_ids.into_iter().map(Into::into).collect(),
+        }
     }
 }
 
@@ -543,61 +1864,322 @@ impl ApprovalTerminateProcessInstanceRequest {
     }
 }
 
-impl ApprovalListProcessInstanceIdsRequest {
-    /// Creates a request with required fields.
-    #[must_use]
-    pub fn new(start_time: i64, end_time: i64, cursor: i64, size: i64) -> Self {
-        Self {
-            start_time,
-            end_time,
-            cursor,
-            size,
-            process_code: None,
-            userid_list: None,
-        }
-    }
-
-    /// Sets process code filter.
-    #[must_use]
-    pub fn process_code(mut self, value: impl Into<String>) -> Self {
-        self.process_code = Some(value.into());
-        self
-    }
+impl ApprovalListProcessInstanceIdsRequest {
+    /// Creates a request with required fields.
+    #[must_use]
+    pub fn new(start_time: i64, end_time: i64, cursor: i64, size: i64) -> Self {
+        Self {
+            start_time,
+            end_time,
+            cursor,
+            size,
+            process_code: None,
+            userid_list: None,
+        }
+    }
+
+    /// Sets process code filter.
+    #[must_use]
+    pub fn process_code(mut self, value: impl Into<String>) -> Self {
+        self.process_code = Some(value.into());
+        self
+    }
+
+    /// Sets user id list filter.
+    #[must_use]
+    pub fn userid_list(mut self, value: impl Into<String>) -> Self {
+        self.userid_list = Some(value.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
+/// Response payload for process-instance id listing.
+pub struct ApprovalListProcessInstanceIdsResult {
+    /// Process instance ids.
+    pub list: Vec<String>,
+    /// Cursor for next page.
+    pub next_cursor: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Typed user object from contact APIs.
+pub struct ContactUser {
+    /// DingTalk user id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub userid: Option<String>,
+    /// DingTalk union id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unionid: Option<String>,
+    /// Display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Mobile number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<String>,
+    /// Mobile country code (for example `86`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_code: Option<String>,
+    /// Whether the mobile number is hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_mobile: Option<bool>,
+    /// Landline extension.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub telephone: Option<String>,
+    /// Avatar URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    /// Employee number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_number: Option<String>,
+    /// Job title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Personal email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Enterprise email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_email: Option<String>,
+    /// Office location.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_place: Option<String>,
+    /// Remark.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remark: Option<String>,
+    /// Departments the user belongs to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dept_id_list: Vec<i64>,
+    /// Sort order of the user within each department.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dept_order_list: Vec<ContactDeptOrder>,
+    /// Whether the user leads each department.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub leader_in_dept: Vec<ContactDeptLeader>,
+    /// Direct manager user id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manager_userid: Option<String>,
+    /// Hire date in milliseconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hired_date: Option<i64>,
+    /// Whether the user has activated DingTalk.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// Whether the user completed real-name authentication.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub real_authed: Option<bool>,
+    /// Whether the user is an administrator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin: Option<bool>,
+    /// Whether the user is the organization owner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boss: Option<bool>,
+    /// Whether the user is a senior executive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub senior: Option<bool>,
+    /// Whether the user is a department leader (list APIs).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leader: Option<bool>,
+    /// Roles assigned to the user.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub role_list: Vec<ContactUserRole>,
+    /// Custom profile fields as a JSON string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extension: Option<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Sort order of a user within one department.
+pub struct ContactDeptOrder {
+    /// Department id.
+    pub dept_id: i64,
+    /// Sort order (larger first).
+    #[serde(default)]
+    pub order: i64,
+}
+
+impl ContactDeptOrder {
+    /// Creates a department sort order entry.
+    #[must_use]
+    pub fn new(dept_id: i64, order: i64) -> Self {
+        Self { dept_id, order }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Job title of a user within one department.
+pub struct ContactDeptTitle {
+    /// Department id.
+    pub dept_id: i64,
+    /// Job title in the department.
+    #[serde(default)]
+    pub title: String,
+}
+
+impl ContactDeptTitle {
+    /// Creates a department job title entry.
+    #[must_use]
+    pub fn new(dept_id: i64, title: impl Into<String>) -> Self {
+        Self {
+            dept_id,
+            title: title.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Whether a user leads one department.
+pub struct ContactDeptLeader {
+    /// Department id.
+    pub dept_id: i64,
+    /// Whether the user is the department leader.
+    #[serde(default)]
+    pub leader: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Role assigned to a user.
+pub struct ContactUserRole {
+    /// Role id.
+    pub id: i64,
+    /// Role name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Role group name.
+    #[serde(default)]
+    pub group_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Response payload for contact user listing.
+pub struct ContactListUsersResult {
+    /// Whether there are more records.
+    #[serde(default)]
+    pub has_more: Option<bool>,
+    /// Cursor for the next page.
+    #[serde(default)]
+    pub next_cursor: Option<i64>,
+    /// User records in this page.
+    #[serde(default)]
+    pub list: Vec<ContactUser>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// User id and name returned by simple user listing.
+pub struct ContactSimpleUser {
+    /// DingTalk user id.
+    pub userid: String,
+    /// Display name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Response payload for simple user listing.
+pub struct ContactListSimpleUsersResult {
+    /// Whether there are more records.
+    #[serde(default)]
+    pub has_more: Option<bool>,
+    /// Cursor for the next page.
+    #[serde(default)]
+    pub next_cursor: Option<i64>,
+    /// User records in this page.
+    #[serde(default)]
+    pub list: Vec<ContactSimpleUser>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Response payload for listing the user ids of a department.
+pub struct ContactListUserIdsResult {
+    /// User ids in the department.
+    #[serde(default)]
+    pub userid_list: Vec<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Response payload for counting users.
+pub struct ContactCountUsersResult {
+    /// Number of users.
+    #[serde(default)]
+    pub count: i64,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
 
-    /// Sets user id list filter.
-    #[must_use]
-    pub fn userid_list(mut self, value: impl Into<String>) -> Self {
-        self.userid_list = Some(value.into());
-        self
-    }
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Organization administrator.
+pub struct ContactAdmin {
+    /// Administrator user id.
+    pub userid: String,
+    /// Administrator level (`1` primary, `2` sub-admin).
+    #[serde(default)]
+    pub sys_level: Option<i64>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
-/// Response payload for process-instance id listing.
-pub struct ApprovalListProcessInstanceIdsResult {
-    /// Process instance ids.
-    pub list: Vec<String>,
-    /// Cursor for next page.
-    pub next_cursor: Option<i64>,
+/// Departments managed by a sub-administrator.
+pub struct ContactAdminScope {
+    /// Managed department ids.
+    #[serde(default)]
+    pub dept_ids: Vec<i64>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
-/// Typed user object from contact APIs.
-pub struct ContactUser {
+/// Identity of the user behind an in-app free-login `code`.
+pub struct UserIdentity {
     /// DingTalk user id.
-    #[serde(default)]
-    pub userid: Option<String>,
+    pub userid: String,
     /// DingTalk union id.
     #[serde(default)]
     pub unionid: Option<String>,
     /// Display name.
     #[serde(default)]
     pub name: Option<String>,
-    /// Mobile number.
+    /// Whether the user is an administrator.
     #[serde(default)]
-    pub mobile: Option<String>,
+    pub sys: bool,
+    /// Administrator level (`1` primary, `2` sub-admin, `100` boss, `0` other).
+    #[serde(default)]
+    pub sys_level: Option<i64>,
+    /// Device id used for the login.
+    #[serde(default)]
+    pub device_id: Option<String>,
     /// Additional response fields not modeled explicitly.
     #[serde(flatten, default)]
     pub extra: BTreeMap<String, Value>,
@@ -605,17 +2187,13 @@ pub struct ContactUser {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
-/// Response payload for contact user listing.
-pub struct ContactListUsersResult {
-    /// Whether there are more records.
-    #[serde(default)]
-    pub has_more: Option<bool>,
-    /// Cursor for the next page.
+/// JSAPI ticket used to sign `dd.config`.
+pub struct JsapiTicket {
+    /// Ticket value.
+    pub ticket: String,
+    /// Lifetime in seconds.
     #[serde(default)]
-    pub next_cursor: Option<i64>,
-    /// User records in this page.
-    #[serde(default)]
-    pub list: Vec<ContactUser>,
+    pub expires_in: Option<i64>,
     /// Additional response fields not modeled explicitly.
     #[serde(flatten, default)]
     pub extra: BTreeMap<String, Value>,
@@ -641,14 +2219,62 @@ pub struct ContactCreateUserResult {
 /// Typed department object from contact APIs.
 pub struct ContactDepartment {
     /// Department id.
-    #[serde(default, alias = "id")]
+    #[serde(default, alias = "id", skip_serializing_if = "Option::is_none")]
     pub dept_id: Option<i64>,
     /// Department name.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// Parent department id.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent_id: Option<i64>,
+    /// Sort order among siblings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<i64>,
+    /// External source identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_identifier: Option<String>,
+    /// Department description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub brief: Option<String>,
+    /// Number of members (when requested).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member_count: Option<i64>,
+    /// Whether a department group chat is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_dept_group: Option<bool>,
+    /// Department group chat id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dept_group_chat_id: Option<String>,
+    /// Whether new members join the group chat automatically.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_add_user: Option<bool>,
+    /// Whether the group chat includes sub-department members.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_contain_sub_dept: Option<bool>,
+    /// Department owner user id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_dept_owner: Option<String>,
+    /// Department manager user ids.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dept_manager_userid_list: Vec<String>,
+    /// Whether the department is hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_dept: Option<bool>,
+    /// Users allowed to see the hidden department.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub user_permits: Vec<String>,
+    /// Departments allowed to see the hidden department.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dept_permits: Vec<i64>,
+    /// Whether members only see permitted contacts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outer_dept: Option<bool>,
+    /// Departments visible to members of a restricted department.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outer_permit_depts: Vec<i64>,
+    /// Users visible to members of a restricted department.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outer_permit_users: Vec<String>,
     /// Additional response fields not modeled explicitly.
     #[serde(flatten, default)]
     pub extra: BTreeMap<String, Value>,
@@ -690,6 +2316,243 @@ pub struct ContactCreateDepartmentResult {
     pub extra: BTreeMap<String, Value>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Response payload for inactive user listing.
+pub struct ContactListInactiveUsersResult {
+    /// Whether there are more records.
+    #[serde(default)]
+    pub has_more: Option<bool>,
+    /// Offset of the next page.
+    #[serde(default)]
+    pub next_cursor: Option<i64>,
+    /// User ids in this page.
+    #[serde(default)]
+    pub list: Vec<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Resigned employee record.
+pub struct ContactEmpLeaveRecord {
+    /// DingTalk user id.
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    /// Display name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Mobile country code.
+    #[serde(default, rename = "stateCode")]
+    pub state_code: Option<String>,
+    /// Mobile number.
+    #[serde(default)]
+    pub mobile: Option<String>,
+    /// Leave time as an ISO-8601 timestamp.
+    #[serde(default, rename = "leaveTime")]
+    pub leave_time: Option<String>,
+    /// How the user left (`oapi`, `cancel` or `SMARTWORK`).
+    #[serde(default, rename = "leaveReason")]
+    pub leave_reason: Option<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Response payload for resigned employee listing.
+pub struct ContactEmpLeaveRecordsResult {
+    /// Token of the next page (absent on the last page).
+    #[serde(default, rename = "nextToken")]
+    pub next_token: Option<String>,
+    /// Records in this page.
+    #[serde(default)]
+    pub records: Vec<ContactEmpLeaveRecord>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Typed external contact object.
+pub struct ContactExternal {
+    /// External contact user id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub userid: Option<String>,
+    /// Contact name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Mobile phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<String>,
+    /// Mobile country code (for example `86`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_code: Option<String>,
+    /// Company name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<String>,
+    /// Job title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// Remark.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remark: Option<String>,
+    /// User id of the employee who follows up the contact.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follower_user_id: Option<String>,
+    /// Label ids.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub label_ids: Vec<i64>,
+    /// Departments the contact is shared with.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub share_dept_ids: Vec<i64>,
+    /// Users the contact is shared with.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub share_user_ids: Vec<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// External contact label.
+pub struct ContactExternalLabel {
+    /// Label id.
+    pub id: i64,
+    /// Label name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// External contact label group.
+pub struct ContactExternalLabelGroup {
+    /// Label group name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Label group color as an ARGB integer.
+    #[serde(default)]
+    pub color: Option<i64>,
+    /// Labels in the group.
+    #[serde(default)]
+    pub labels: Vec<ContactExternalLabel>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Role definition.
+pub struct Role {
+    /// Role id.
+    #[serde(default, alias = "role_id")]
+    pub id: i64,
+    /// Role name.
+    #[serde(default, alias = "role_name")]
+    pub name: Option<String>,
+    /// Role group id.
+    #[serde(default, rename = "groupId", alias = "group_id")]
+    pub group_id: Option<i64>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Role group with its roles.
+pub struct RoleGroup {
+    /// Role group id.
+    #[serde(default, rename = "groupId", alias = "group_id")]
+    pub group_id: i64,
+    /// Role group name.
+    #[serde(default, alias = "group_name")]
+    pub name: Option<String>,
+    /// Roles in the group.
+    #[serde(default, alias = "role_list")]
+    pub roles: Vec<Role>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Response payload for role group listing.
+pub struct RoleListResult {
+    /// Whether there are more records.
+    #[serde(default, rename = "hasMore", alias = "has_more")]
+    pub has_more: Option<bool>,
+    /// Role groups in this page.
+    #[serde(default)]
+    pub list: Vec<RoleGroup>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Department a role holder manages through the role.
+pub struct RoleManageScope {
+    /// Department id.
+    pub dept_id: i64,
+    /// Department name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// User that holds a role.
+pub struct RoleUser {
+    /// DingTalk user id.
+    pub userid: String,
+    /// Display name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Departments managed through the role.
+    #[serde(default, rename = "manageScopes", alias = "manage_scopes")]
+    pub manage_scopes: Vec<RoleManageScope>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Response payload for listing the users of a role.
+pub struct RoleListUsersResult {
+    /// Whether there are more records.
+    #[serde(default, rename = "hasMore", alias = "has_more")]
+    pub has_more: Option<bool>,
+    /// Offset of the next page.
+    #[serde(default, rename = "nextCursor", alias = "next_cursor")]
+    pub next_cursor: Option<i64>,
+    /// Users in this page.
+    #[serde(default)]
+    pub list: Vec<RoleUser>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
 /// Typed approval process instance payload.
@@ -702,10 +2565,103 @@ pub struct ApprovalProcessInstance {
     pub extra: BTreeMap<String, Value>,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Response payload for enterprise robot group messages.
+pub struct GroupMessageSendResponse {
+    /// Key for querying message read status.
+    #[serde(default, rename = "processQueryKey")]
+    pub process_query_key: Option<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+    /// Raw response body, kept for debugging.
+    #[serde(skip)]
+    pub raw_body: String,
+}
+
+impl RawBodyResponse for GroupMessageSendResponse {
+    fn set_raw_body(&mut self, body: String) {
+        self.raw_body = body;
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Response payload for enterprise robot one-to-one batch messages.
+pub struct OtoBatchSendResponse {
+    /// Key for querying message read status.
+    #[serde(default, rename = "processQueryKey")]
+    pub process_query_key: Option<String>,
+    /// Staff ids that were rejected as invalid.
+    #[serde(default, rename = "invalidStaffIdList")]
+    pub invalid_staff_id_list: Vec<String>,
+    /// Staff ids skipped due to flow control.
+    #[serde(default, rename = "flowControlledStaffIdList")]
+    pub flow_controlled_staff_id_list: Vec<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+    /// Raw response body, kept for debugging.
+    #[serde(skip)]
+    pub raw_body: String,
+}
+
+impl RawBodyResponse for OtoBatchSendResponse {
+    fn set_raw_body(&mut self, body: String) {
+        self.raw_body = body;
+    }
+}
+
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+/// Response of enterprise `reply_message`, depending on conversation type.
+pub enum ReplyMessageResponse {
+    /// Reply sent as a one-to-one message.
+    Private(OtoBatchSendResponse),
+    /// Reply sent as a group message.
+    Group(GroupMessageSendResponse),
+}
+
+impl ReplyMessageResponse {
+    /// Returns the key for querying message read status.
+    #[must_use]
+    pub fn process_query_key(&self) -> Option<&str> {
+        match self {
+            Self::Private(response) => response.process_query_key.as_deref(),
+            Self::Group(response) => response.process_query_key.as_deref(),
+        }
+    }
+
+    /// Returns the raw response body.
+    #[must_use]
+    pub fn raw_body(&self) -> &str {
+        match self {
+            Self::Private(response) => &response.raw_body,
+            Self::Group(response) => &response.raw_body,
+        }
+    }
+}
+
+/// Serializes a list as the comma-separated string DingTalk expects (`"1,2,3"`).
+fn serialize_comma_separated<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer,
+{
+    let joined = values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+    serializer.serialize_str(&joined)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        ApprovalProcessInstance, ApprovalTerminateProcessInstanceRequest, ContactListUsersResult,
+        ApprovalProcessInstance, ApprovalTerminateProcessInstanceRequest, ContactCreateUserRequest,
+        ContactDepartment, ContactListUsersResult, ContactUpdateDepartmentRequest, ContactUser,
     };
 
     #[test]
@@ -760,4 +2716,110 @@ mod tests {
             Some("BIZ-1")
         );
     }
+
+    #[test]
+    fn contact_user_documented_fields_round_trip() {
+        let raw = serde_json::json!({
+            "userid": "u-1",
+            "unionid": "union-1",
+            "name": "Alice",
+            "mobile": "13800000000",
+            "title": "Engineer",
+            "email": "alice@example.com",
+            "job_number": "E001",
+            "dept_id_list": [1, 2],
+            "dept_order_list": [{"dept_id": 1, "order": 10}],
+            "leader_in_dept": [{"dept_id": 2, "leader": true}],
+            "role_list": [{"id": 7, "name": "Manager", "group_name": "Default"}],
+            "manager_userid": "boss-1",
+            "hired_date": 1_700_000_000_000_i64,
+            "active": true,
+            "admin": false,
+            "boss": false,
+            "avatar": "https://img.example.com/a.png",
+            "extension": "{\"badge\":\"A1\"}",
+            "union_emp_ext": {"corpId": "ding-other"}
+        });
+        let user: ContactUser = serde_json::from_value(raw.clone()).expect("user should parse");
+
+        assert_eq!(user.dept_id_list, [1, 2]);
+        assert_eq!(user.leader_in_dept[0].dept_id, 2);
+        assert!(user.leader_in_dept[0].leader);
+        assert_eq!(user.hired_date, Some(1_700_000_000_000));
+        assert_eq!(user.role_list[0].name.as_deref(), Some("Manager"));
+        assert_eq!(user.manager_userid.as_deref(), Some("boss-1"));
+        assert_eq!(user.active, Some(true));
+        assert!(!user.extra.contains_key("title"));
+        assert!(user.extra.contains_key("union_emp_ext"));
+        assert_eq!(serde_json::to_value(&user).expect("serialize"), raw);
+    }
+
+    #[test]
+    fn contact_department_documented_fields_round_trip() {
+        let raw = serde_json::json!({
+            "dept_id": 2,
+            "name": "R&D",
+            "parent_id": 1,
+            "order": 3,
+            "dept_manager_userid_list": ["u-1"],
+            "hide_dept": false,
+            "outer_permit_depts": [5],
+            "member_count": 12,
+            "code": "RD"
+        });
+        let department: ContactDepartment =
+            serde_json::from_value(raw.clone()).expect("department should parse");
+
+        assert_eq!(department.dept_manager_userid_list, ["u-1"]);
+        assert_eq!(department.outer_permit_depts, [5]);
+        assert_eq!(department.member_count, Some(12));
+        assert_eq!(department.extra.len(), 1);
+        assert_eq!(serde_json::to_value(&department).expect("serialize"), raw);
+    }
+
+    #[test]
+    fn contact_create_user_request_serializes_typed_fields() {
+        let request = ContactCreateUserRequest::new("Alice", "13800000000", [1, 2])
+            .title("Engineer")
+            .hired_date(1_700_000_000_000)
+            .dept_title(2, "Lead")
+            .dept_order(2, 10)
+            .insert_extra("custom", "value");
+
+        let value = serde_json::to_value(request).expect("request should serialize");
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "name": "Alice",
+                "mobile": "13800000000",
+                "dept_id_list": "1,2",
+                "title": "Engineer",
+                "hired_date": 1_700_000_000_000_i64,
+                "dept_title_list": [{"dept_id": 2, "title": "Lead"}],
+                "dept_order_list": [{"dept_id": 2, "order": 10}],
+                "custom": "value",
+            })
+        );
+    }
+
+    #[test]
+    fn contact_update_department_request_joins_id_lists() {
+        let request = ContactUpdateDepartmentRequest::new(5)
+            .hide_dept(true)
+            .dept_permits([7, 8])
+            .user_permits(["u1", "u2"])
+            .dept_manager_userid_list(["boss"]);
+
+        let value = serde_json::to_value(request).expect("request should serialize");
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "dept_id": 5,
+                "hide_dept": true,
+                "dept_permits": "7,8",
+                "user_permits": "u1,u2",
+                "dept_manager_userid_list": "boss",
+            })
+        );
+    }
 }
@@ -0,0 +1,258 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize, de};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Address book user change event (`user_add_org`, `user_modify_org`, ...).
+pub struct ContactUserEvent {
+    /// Changed user ids, matching [`ContactUser::userid`](crate::ContactUser).
+    #[serde(default, rename = "UserId")]
+    pub user_ids: Vec<String>,
+    /// Corp id the event belongs to.
+    #[serde(default, rename = "CorpId")]
+    pub corp_id: Option<String>,
+    /// Event time as a millisecond Unix timestamp.
+    #[serde(
+        default,
+        rename = "TimeStamp",
+        deserialize_with = "deserialize_optional_i64"
+    )]
+    pub timestamp: Option<i64>,
+    /// Additional fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Address book department change event (`org_dept_create`, `org_dept_modify`, ...).
+pub struct ContactDepartmentEvent {
+    /// Changed department ids, matching [`ContactDepartment::dept_id`](crate::ContactDepartment).
+    #[serde(default, rename = "DeptId", deserialize_with = "deserialize_i64_list")]
+    pub dept_ids: Vec<i64>,
+    /// Corp id the event belongs to.
+    #[serde(default, rename = "CorpId")]
+    pub corp_id: Option<String>,
+    /// Event time as a millisecond Unix timestamp.
+    #[serde(
+        default,
+        rename = "TimeStamp",
+        deserialize_with = "deserialize_optional_i64"
+    )]
+    pub timestamp: Option<i64>,
+    /// Additional fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Approval instance or task change event (`bpms_instance_change`, `bpms_task_change`).
+pub struct ApprovalChangeEvent {
+    /// Approval process instance id, matching
+    /// [`ApprovalProcessInstance::process_instance_id`](crate::ApprovalProcessInstance).
+    #[serde(default, rename = "processInstanceId")]
+    pub process_instance_id: Option<String>,
+    /// Approval process code.
+    #[serde(default, rename = "processCode")]
+    pub process_code: Option<String>,
+    /// Corp id the event belongs to.
+    #[serde(default, rename = "corpId")]
+    pub corp_id: Option<String>,
+    /// Change type (`start`, `finish`, `terminate`, `cancel`).
+    #[serde(default, rename = "type")]
+    pub change_type: Option<String>,
+    /// Outcome for finished instances or tasks (`agree`, `refuse`).
+    #[serde(default)]
+    pub result: Option<String>,
+    /// User id of the originator (instance events) or task handler (task events).
+    #[serde(default, rename = "staffId")]
+    pub staff_id: Option<String>,
+    /// Approval title.
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Task remark, present on task events.
+    #[serde(default)]
+    pub remark: Option<String>,
+    /// Creation time as a millisecond Unix timestamp.
+    #[serde(
+        default,
+        rename = "createTime",
+        deserialize_with = "deserialize_optional_i64"
+    )]
+    pub create_time: Option<i64>,
+    /// Finish time as a millisecond Unix timestamp.
+    #[serde(
+        default,
+        rename = "finishTime",
+        deserialize_with = "deserialize_optional_i64"
+    )]
+    pub finish_time: Option<i64>,
+    /// Additional fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
+#[serde(try_from = "Value")]
+/// Decrypted HTTP event subscription payload, keyed by its `EventType`.
+pub enum DingTalkEvent {
+    /// `check_url`: callback URL registration probe.
+    CheckUrl,
+    /// `user_add_org`: users joined the organization.
+    UserAddOrg(ContactUserEvent),
+    /// `user_modify_org`: user profiles changed.
+    UserModifyOrg(ContactUserEvent),
+    /// `user_leave_org`: users left the organization.
+    UserLeaveOrg(ContactUserEvent),
+    /// `user_active_org`: users activated their accounts.
+    UserActiveOrg(ContactUserEvent),
+    /// `org_dept_create`: departments created.
+    OrgDeptCreate(ContactDepartmentEvent),
+    /// `org_dept_modify`: departments changed.
+    OrgDeptModify(ContactDepartmentEvent),
+    /// `org_dept_remove`: departments removed.
+    OrgDeptRemove(ContactDepartmentEvent),
+    /// `bpms_instance_change`: approval instance started, finished, or terminated.
+    BpmsInstanceChange(ApprovalChangeEvent),
+    /// `bpms_task_change`: approval task started, finished, or cancelled.
+    BpmsTaskChange(ApprovalChangeEvent),
+    /// Event type not modeled explicitly; carries the full payload.
+    Unknown(Value),
+}
+
+impl DingTalkEvent {
+    /// Returns the `EventType` of the payload.
+    #[must_use]
+    pub fn event_type(&self) -> Option<&str> {
+        Some(match self {
+            Self::CheckUrl => "check_url",
+            Self::UserAddOrg(_) => "user_add_org",
+            Self::UserModifyOrg(_) => "user_modify_org",
+            Self::UserLeaveOrg(_) => "user_leave_org",
+            Self::UserActiveOrg(_) => "user_active_org",
+            Self::OrgDeptCreate(_) => "org_dept_create",
+            Self::OrgDeptModify(_) => "org_dept_modify",
+            Self::OrgDeptRemove(_) => "org_dept_remove",
+            Self::BpmsInstanceChange(_) => "bpms_instance_change",
+            Self::BpmsTaskChange(_) => "bpms_task_change",
+            Self::Unknown(payload) => return payload.get("EventType")?.as_str(),
+        })
+    }
+}
+
+impl TryFrom<Value> for DingTalkEvent {
+    type Error = serde_json::Error;
+
+    fn try_from(payload: Value) -> Result<Self, Self::Error> {
+        let event_type = payload
+            .get("EventType")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        Ok(match event_type {
+            "check_url" => Self::CheckUrl,
+            "user_add_org" => Self::UserAddOrg(serde_json::from_value(payload)?),
+            "user_modify_org" => Self::UserModifyOrg(serde_json::from_value(payload)?),
+            "user_leave_org" => Self::UserLeaveOrg(serde_json::from_value(payload)?),
+            "user_active_org" => Self::UserActiveOrg(serde_json::from_value(payload)?),
+            "org_dept_create" => Self::OrgDeptCreate(serde_json::from_value(payload)?),
+            "org_dept_modify" => Self::OrgDeptModify(serde_json::from_value(payload)?),
+            "org_dept_remove" => Self::OrgDeptRemove(serde_json::from_value(payload)?),
+            "bpms_instance_change" => Self::BpmsInstanceChange(serde_json::from_value(payload)?),
+            "bpms_task_change" => Self::BpmsTaskChange(serde_json::from_value(payload)?),
+            _ => Self::Unknown(payload),
+        })
+    }
+}
+
+fn value_as_i64(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(number) => number.as_i64(),
+        Value::String(text) => text.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn deserialize_optional_i64<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Value>::deserialize(deserializer)?
+        .as_ref()
+        .and_then(value_as_i64))
+}
+
+fn deserialize_i64_list<'de, D>(deserializer: D) -> Result<Vec<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<Vec<Value>>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .iter()
+        .map(|value| {
+            value_as_i64(value)
+                .ok_or_else(|| de::Error::custom(format!("invalid integer id: {value}")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DingTalkEvent;
+
+    #[test]
+    fn dingtalk_event_parses_contact_and_department_events() {
+        let event: DingTalkEvent = serde_json::from_str(
+            r#"{"EventType":"user_add_org","TimeStamp":"1700000000000","UserId":["u1","u2"],"CorpId":"ding1"}"#,
+        )
+        .expect("event should deserialize");
+        let DingTalkEvent::UserAddOrg(user) = &event else {
+            panic!("expected user_add_org, got {event:?}");
+        };
+        assert_eq!(user.user_ids, ["u1", "u2"]);
+        assert_eq!(user.timestamp, Some(1_700_000_000_000));
+        assert_eq!(event.event_type(), Some("user_add_org"));
+
+        let event: DingTalkEvent = serde_json::from_str(
+            r#"{"EventType":"org_dept_remove","TimeStamp":1700000000000,"DeptId":[10,"11"]}"#,
+        )
+        .expect("event should deserialize");
+        let DingTalkEvent::OrgDeptRemove(dept) = event else {
+            panic!("expected org_dept_remove");
+        };
+        assert_eq!(dept.dept_ids, [10, 11]);
+
+        serde_json::from_str::<DingTalkEvent>(
+            r#"{"EventType":"org_dept_remove","DeptId":[10,"eleven"]}"#,
+        )
+        .expect_err("unparsable department id should fail");
+    }
+
+    #[test]
+    fn dingtalk_event_parses_approval_check_url_and_unknown_events() {
+        let event: DingTalkEvent = serde_json::from_str(
+            r#"{"EventType":"bpms_instance_change","processInstanceId":"proc-1","processCode":"PROC-A","type":"finish","result":"agree","staffId":"u1","createTime":1700000000000,"finishTime":1700000100000,"url":"https://aflow.dingtalk.com"}"#,
+        )
+        .expect("event should deserialize");
+        let DingTalkEvent::BpmsInstanceChange(change) = event else {
+            panic!("expected bpms_instance_change");
+        };
+        assert_eq!(change.process_instance_id.as_deref(), Some("proc-1"));
+        assert_eq!(change.result.as_deref(), Some("agree"));
+        assert_eq!(change.finish_time, Some(1_700_000_100_000));
+        assert!(change.extra.contains_key("url"));
+
+        let event: DingTalkEvent =
+            serde_json::from_str(r#"{"EventType":"check_url"}"#).expect("event should deserialize");
+        assert!(matches!(event, DingTalkEvent::CheckUrl));
+
+        let event: DingTalkEvent =
+            serde_json::from_str(r#"{"EventType":"label_user_change","LabelIdList":[1]}"#)
+                .expect("event should deserialize");
+        assert!(matches!(event, DingTalkEvent::Unknown(_)));
+        assert_eq!(event.event_type(), Some("label_user_change"));
+    }
+}
@@ -1,105 +1,120 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
-use crate::types::enterprise::ApprovalProcessInstance;
-use crate::types::webhook::{ActionCardButton, FeedCardLink};
+use crate::types::enterprise::{ApprovalProcessInstance, Role, RoleGroup};
+#[cfg(feature = "stream")]
+use crate::types::stream::StreamSubscription;
+use crate::types::webhook::{ActionCardButton, FeedCardLink, WebhookAt, WebhookMessage};
 
 #[derive(Serialize)]
 #[serde(tag = "msgtype")]
-pub(crate) enum WebhookMessage {
+pub(crate) enum WebhookPayload<'a> {
     #[serde(rename = "text")]
     Text {
-        text: TextContent,
+        text: TextContent<'a>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        at: Option<At>,
+        at: Option<&'a WebhookAt>,
     },
     #[serde(rename = "link")]
-    Link {
-        link: LinkContent,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        at: Option<At>,
-    },
+    Link { link: LinkContent<'a> },
     #[serde(rename = "markdown")]
     Markdown {
-        markdown: MarkdownContent,
+        markdown: MarkdownContent<'a>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        at: Option<At>,
+        at: Option<&'a WebhookAt>,
     },
     #[serde(rename = "actionCard")]
     ActionCard {
         #[serde(rename = "actionCard")]
-        action_card: ActionCardContent,
+        action_card: ActionCardContent<'a>,
     },
     #[serde(rename = "feedCard")]
     FeedCard {
         #[serde(rename = "feedCard")]
-        feed_card: FeedCardContent,
+        feed_card: FeedCardContent<'a>,
     },
 }
 
+impl<'a> From<&'a WebhookMessage> for WebhookPayload<'a> {
+    fn from(message: &'a WebhookMessage) -> Self {
+        match message {
+            WebhookMessage::Text(message) => Self::Text {
+                text: TextContent {
+                    content: &message.content,
+                },
+                at: message.at.as_ref(),
+            },
+            WebhookMessage::Link(message) => Self::Link {
+                link: LinkContent {
+                    title: &message.title,
+                    text: &message.text,
+                    message_url: &message.message_url,
+                    pic_url: message.pic_url.as_deref(),
+                },
+            },
+            WebhookMessage::Markdown(message) => Self::Markdown {
+                markdown: MarkdownContent {
+                    title: &message.title,
+                    text: &message.text,
+                },
+                at: message.at.as_ref(),
+            },
+            WebhookMessage::ActionCard(message) => Self::ActionCard {
+                action_card: ActionCardContent {
+                    title: &message.title,
+                    text: &message.text,
+                    btn_orientation: message.btn_orientation.as_deref(),
+                    single_title: message.single_title.as_deref(),
+                    single_url: message.single_url.as_deref(),
+                    btns: message.btns.as_deref(),
+                },
+            },
+            WebhookMessage::FeedCard(message) => Self::FeedCard {
+                feed_card: FeedCardContent {
+                    links: &message.links,
+                },
+            },
+        }
+    }
+}
+
 #[derive(Serialize)]
-pub(crate) struct TextContent {
-    pub(crate) content: String,
+pub(crate) struct TextContent<'a> {
+    pub(crate) content: &'a str,
 }
 
 #[derive(Serialize)]
-pub(crate) struct LinkContent {
-    pub(crate) title: String,
-    pub(crate) text: String,
+pub(crate) struct LinkContent<'a> {
+    pub(crate) title: &'a str,
+    pub(crate) text: &'a str,
     #[serde(rename = "messageUrl")]
-    pub(crate) message_url: String,
+    pub(crate) message_url: &'a str,
     #[serde(rename = "picUrl", skip_serializing_if = "Option::is_none")]
-    pub(crate) pic_url: Option<String>,
+    pub(crate) pic_url: Option<&'a str>,
 }
 
 #[derive(Serialize)]
-pub(crate) struct MarkdownContent {
-    pub(crate) title: String,
-    pub(crate) text: String,
+pub(crate) struct MarkdownContent<'a> {
+    pub(crate) title: &'a str,
+    pub(crate) text: &'a str,
 }
 
 #[derive(Serialize)]
-pub(crate) struct ActionCardContent {
-    pub(crate) title: String,
-    pub(crate) text: String,
+pub(crate) struct ActionCardContent<'a> {
+    pub(crate) title: &'a str,
+    pub(crate) text: &'a str,
     #[serde(rename = "btnOrientation", skip_serializing_if = "Option::is_none")]
-    pub(crate) btn_orientation: Option<String>,
+    pub(crate) btn_orientation: Option<&'a str>,
     #[serde(rename = "singleTitle", skip_serializing_if = "Option::is_none")]
-    pub(crate) single_title: Option<String>,
+    pub(crate) single_title: Option<&'a str>,
     #[serde(rename = "singleURL", skip_serializing_if = "Option::is_none")]
-    pub(crate) single_url: Option<String>,
+    pub(crate) single_url: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) btns: Option<Vec<ActionCardButton>>,
+    pub(crate) btns: Option<&'a [ActionCardButton]>,
 }
 
 #[derive(Serialize)]
-pub(crate) struct FeedCardContent {
-    pub(crate) links: Vec<FeedCardLink>,
-}
-
-#[derive(Serialize)]
-pub(crate) struct At {
-    #[serde(rename = "atMobiles", skip_serializing_if = "Option::is_none")]
-    pub(crate) at_mobiles: Option<Vec<String>>,
-    #[serde(rename = "atUserIds", skip_serializing_if = "Option::is_none")]
-    pub(crate) at_user_ids: Option<Vec<String>>,
-    #[serde(rename = "isAtAll", skip_serializing_if = "Option::is_none")]
-    pub(crate) is_at_all: Option<bool>,
-}
-
-pub(crate) fn build_at(
-    at_mobiles: Option<Vec<String>>,
-    at_user_ids: Option<Vec<String>>,
-    is_at_all: Option<bool>,
-) -> Option<At> {
-    if at_mobiles.is_some() || at_user_ids.is_some() || is_at_all.is_some() {
-        Some(At {
-            at_mobiles,
-            at_user_ids,
-            is_at_all,
-        })
-    } else {
-        None
-    }
+pub(crate) struct FeedCardContent<'a> {
+    pub(crate) links: &'a [FeedCardLink],
 }
 
 #[derive(Serialize)]
@@ -141,10 +156,92 @@ pub(crate) struct OtoMessageRequest<'a> {
     pub(crate) user_ids: Vec<&'a str>,
 }
 
+#[cfg(feature = "stream")]
+#[derive(Serialize)]
+pub(crate) struct StreamOpenRequest<'a> {
+    #[serde(rename = "clientId")]
+    pub(crate) client_id: &'a str,
+    #[serde(rename = "clientSecret")]
+    pub(crate) client_secret: &'a str,
+    pub(crate) subscriptions: &'a [StreamSubscription],
+    pub(crate) ua: &'a str,
+}
+
+#[cfg(feature = "stream")]
+#[derive(Deserialize)]
+pub(crate) struct StreamOpenResponse {
+    pub(crate) endpoint: String,
+    pub(crate) ticket: String,
+}
+
+#[cfg(feature = "stream")]
+#[derive(Serialize)]
+pub(crate) struct StreamAckFrame<'a> {
+    pub(crate) code: u16,
+    pub(crate) headers: StreamAckHeaders<'a>,
+    pub(crate) message: &'a str,
+    #[serde(serialize_with = "serialize_to_json_string")]
+    pub(crate) data: &'a serde_json::Value,
+}
+
+#[cfg(feature = "stream")]
+#[derive(Serialize)]
+pub(crate) struct StreamAckHeaders<'a> {
+    #[serde(rename = "contentType")]
+    pub(crate) content_type: &'a str,
+    #[serde(rename = "messageId")]
+    pub(crate) message_id: &'a str,
+}
+
+/// Typed send responses that keep the raw body available to callers.
+pub(crate) trait RawBodyResponse: DeserializeOwned + Default {
+    fn set_raw_body(&mut self, body: String);
+}
+
+#[derive(Serialize)]
+pub(crate) struct UserInfoByCodeRequest<'a> {
+    pub(crate) code: &'a str,
+}
+
+#[derive(Serialize)]
+pub(crate) struct OAuth2AccessTokenRequest<'a> {
+    #[serde(rename = "appKey")]
+    pub(crate) app_key: &'a str,
+    #[serde(rename = "appSecret")]
+    pub(crate) app_secret: &'a str,
+}
+
+#[derive(Serialize)]
+pub(crate) struct UserAccessTokenRequest<'a> {
+    #[serde(rename = "clientId")]
+    pub(crate) client_id: &'a str,
+    #[serde(rename = "clientSecret")]
+    pub(crate) client_secret: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) code: Option<&'a str>,
+    #[serde(rename = "refreshToken", skip_serializing_if = "Option::is_none")]
+    pub(crate) refresh_token: Option<&'a str>,
+    #[serde(rename = "grantType")]
+    pub(crate) grant_type: &'static str,
+}
+
+#[derive(Serialize)]
+pub(crate) struct CorpAccessTokenRequest<'a> {
+    #[serde(rename = "suiteKey")]
+    pub(crate) suite_key: &'a str,
+    #[serde(rename = "suiteSecret")]
+    pub(crate) suite_secret: &'a str,
+    #[serde(rename = "authCorpId")]
+    pub(crate) auth_corp_id: &'a str,
+    #[serde(rename = "suiteTicket")]
+    pub(crate) suite_ticket: &'a str,
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct GetTokenResponse {
+    #[serde(default)]
     pub(crate) errcode: i64,
-    #[serde(alias = "message")]
+    #[serde(default, alias = "message")]
     pub(crate) errmsg: String,
     #[serde(alias = "accessToken")]
     pub(crate) access_token: Option<String>,
@@ -182,6 +279,50 @@ pub(crate) struct TopApiSimpleResponse {
     pub(crate) request_id: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub(crate) struct ParentDepartmentIdsResult {
+    #[serde(default)]
+    pub(crate) parent_id_list: Vec<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct UserParentDepartmentsResult {
+    #[serde(default)]
+    pub(crate) parent_list: Vec<ParentDepartmentIdsPath>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ParentDepartmentIdsPath {
+    #[serde(default)]
+    pub(crate) parent_dept_id_list: Vec<i64>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct ExternalContactPayload<'a, T> {
+    pub(crate) contact: &'a T,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ExternalContactCreateResponse {
+    pub(crate) userid: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct TopApiResultsResponse<T> {
+    #[serde(default = "Vec::new")]
+    pub(crate) results: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct RoleGroupResponse {
+    pub(crate) role_group: RoleGroup,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct RoleResponse {
+    pub(crate) role: Role,
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct ApprovalCreateProcessInstanceResponse {
     pub(crate) errcode: i64,
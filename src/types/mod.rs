@@ -1,21 +1,68 @@
+/// Robot callback message types.
+pub mod callback;
 /// Enterprise API request/response types.
 pub mod enterprise;
+/// HTTP event subscription payload types.
+pub mod event;
 pub(crate) mod internal;
+/// User OAuth login types.
+pub mod oauth;
+/// Organization snapshot types.
+pub mod org;
+#[cfg(feature = "stream")]
+/// Stream Mode frame and subscription types.
+pub mod stream;
 /// Webhook message helper types.
 pub mod webhook;
 
+/// Re-exported robot callback message types.
+pub use callback::{
+    RobotAtUser, RobotAudioContent, RobotCallbackMessage, RobotFileContent, RobotMessageContent,
+    RobotPictureContent, RobotRichTextContent, RobotRichTextItem, RobotTextContent,
+};
 /// Re-exported enterprise request/response types.
 pub use enterprise::{
     ApprovalCreateProcessInstanceRequest, ApprovalFormComponentValue,
     ApprovalListProcessInstanceIdsRequest, ApprovalListProcessInstanceIdsResult,
-    ApprovalProcessInstance, ApprovalTerminateProcessInstanceRequest,
-    ContactCreateDepartmentRequest, ContactCreateDepartmentResult, ContactCreateUserRequest,
-    ContactCreateUserResult, ContactDeleteDepartmentRequest, ContactDeleteUserRequest,
-    ContactDepartment, ContactGetDepartmentRequest, ContactGetUserByMobileRequest,
-    ContactGetUserByUnionIdRequest, ContactGetUserRequest, ContactListSubDepartmentIdsRequest,
-    ContactListSubDepartmentIdsResult, ContactListSubDepartmentsRequest,
-    ContactListSubDepartmentsResult, ContactListUsersRequest, ContactListUsersResult,
-    ContactUpdateDepartmentRequest, ContactUpdateUserRequest, ContactUser,
+    ApprovalProcessInstance, ApprovalTerminateProcessInstanceRequest, ContactAdmin,
+    ContactAdminScope, ContactCountUsersRequest, ContactCountUsersResult,
+    ContactCreateDepartmentRequest, ContactCreateDepartmentResult,
+    ContactCreateExternalContactRequest, ContactCreateUserRequest, ContactCreateUserResult,
+    ContactDeleteDepartmentRequest, ContactDeleteExternalContactRequest, ContactDeleteUserRequest,
+    ContactDepartment, ContactDeptLeader, ContactDeptOrder, ContactDeptTitle,
+    ContactEmpLeaveRecord, ContactEmpLeaveRecordsResult, ContactExternal, ContactExternalLabel,
+    ContactExternalLabelGroup, ContactGetAdminScopeRequest, ContactGetDepartmentRequest,
+    ContactGetExternalContactRequest, ContactGetUserByMobileRequest,
+    ContactGetUserByUnionIdRequest, ContactGetUserRequest, ContactListEmpLeaveRecordsRequest,
+    ContactListExternalContactsRequest, ContactListExternalLabelGroupsRequest,
+    ContactListInactiveUsersRequest, ContactListInactiveUsersResult,
+    ContactListParentDepartmentsByDeptRequest, ContactListParentDepartmentsByUserRequest,
+    ContactListSimpleUsersRequest, ContactListSimpleUsersResult,
+    ContactListSubDepartmentIdsRequest, ContactListSubDepartmentIdsResult,
+    ContactListSubDepartmentsRequest, ContactListSubDepartmentsResult, ContactListUserIdsRequest,
+    ContactListUserIdsResult, ContactListUsersRequest, ContactListUsersResult, ContactSimpleUser,
+    ContactUpdateDepartmentRequest, ContactUpdateExternalContactRequest, ContactUpdateUserRequest,
+    ContactUser, ContactUserRole, GroupMessageSendResponse, JsapiTicket, OtoBatchSendResponse,
+    ReplyMessageResponse, Role, RoleAssignmentRequest, RoleGetRoleGroupRequest, RoleGetRoleRequest,
+    RoleGroup, RoleListRequest, RoleListResult, RoleListUsersRequest, RoleListUsersResult,
+    RoleManageScope, RoleUser, UserIdentity,
+};
+/// Re-exported event subscription payload types.
+pub use event::{ApprovalChangeEvent, ContactDepartmentEvent, ContactUserEvent, DingTalkEvent};
+/// Re-exported user OAuth login types.
+pub use oauth::{OAuthAuthorizeRequest, OAuthUserProfile, UserAccessToken};
+/// Re-exported organization snapshot types.
+pub use org::{
+    DepartmentMove, FieldChange, OrgDepartment, OrgDiff, OrgSnapshot, OrgUser, UserChange,
+};
+#[cfg(feature = "stream")]
+/// Re-exported Stream Mode types.
+pub use stream::{
+    STREAM_TOPIC_CARD_CALLBACK, STREAM_TOPIC_ROBOT_MESSAGE, StreamAck, StreamFrame,
+    StreamFrameType, StreamHeaders, StreamSubscription,
 };
 /// Re-exported webhook message helper types.
-pub use webhook::{ActionCardButton, FeedCardLink};
+pub use webhook::{
+    ActionCardButton, ActionCardMessage, FeedCardLink, FeedCardMessage, LinkMessage,
+    MarkdownMessage, TextMessage, WebhookAt, WebhookMessage, WebhookSendResponse,
+};
@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Parameters of the `login.dingtalk.com/oauth2/auth` authorization URL.
+#[derive(Debug, Clone)]
+pub struct OAuthAuthorizeRequest {
+    /// URL DingTalk redirects back to with `authCode` and `state`.
+    pub redirect_uri: String,
+    /// Opaque value echoed back to the redirect URI.
+    pub state: Option<String>,
+    /// Requested scope (`openid`, or `openid corpid`).
+    pub scope: String,
+    /// Consent prompt mode.
+    pub prompt: String,
+    /// Restricts login to one organization.
+    pub corp_id: Option<String>,
+}
+
+impl OAuthAuthorizeRequest {
+    /// Creates a request with `scope=openid` and `prompt=consent`.
+    #[must_use]
+    pub fn new(redirect_uri: impl Into<String>) -> Self {
+        Self {
+            redirect_uri: redirect_uri.into(),
+            state: None,
+            scope: "openid".to_string(),
+            prompt: "consent".to_string(),
+            corp_id: None,
+        }
+    }
+
+    /// Sets the `state` value.
+    #[must_use]
+    pub fn state(mut self, value: impl Into<String>) -> Self {
+        self.state = Some(value.into());
+        self
+    }
+
+    /// Sets the requested scope.
+    #[must_use]
+    pub fn scope(mut self, value: impl Into<String>) -> Self {
+        self.scope = value.into();
+        self
+    }
+
+    /// Sets the prompt mode.
+    #[must_use]
+    pub fn prompt(mut self, value: impl Into<String>) -> Self {
+        self.prompt = value.into();
+        self
+    }
+
+    /// Restricts login to the given organization.
+    #[must_use]
+    pub fn corp_id(mut self, value: impl Into<String>) -> Self {
+        self.corp_id = Some(value.into());
+        self
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// User access token issued by `/v1.0/oauth2/userAccessToken`.
+///
+/// `Debug` output redacts both tokens.
+pub struct UserAccessToken {
+    /// User access token.
+    #[serde(rename = "accessToken")]
+    pub access_token: String,
+    /// Refresh token used to obtain a new access token.
+    #[serde(default, rename = "refreshToken")]
+    pub refresh_token: Option<String>,
+    /// Lifetime in seconds.
+    #[serde(default, rename = "expireIn")]
+    pub expire_in: Option<i64>,
+    /// Organization selected during login (with the `corpid` scope).
+    #[serde(default, rename = "corpId")]
+    pub corp_id: Option<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl std::fmt::Debug for UserAccessToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UserAccessToken")
+            .field("access_token", &"<redacted>")
+            .field(
+                "refresh_token",
+                &self.refresh_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("expire_in", &self.expire_in)
+            .field("corp_id", &self.corp_id)
+            .field("extra", &self.extra)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Profile of the logged-in user from `/v1.0/contact/users/me`.
+pub struct OAuthUserProfile {
+    /// Nickname.
+    #[serde(default)]
+    pub nick: Option<String>,
+    /// Avatar URL.
+    #[serde(default, rename = "avatarUrl")]
+    pub avatar_url: Option<String>,
+    /// Mobile number (requires the mobile permission).
+    #[serde(default)]
+    pub mobile: Option<String>,
+    /// Open id, unique per app.
+    #[serde(default, rename = "openId")]
+    pub open_id: Option<String>,
+    /// Union id, unique per developer.
+    #[serde(default, rename = "unionId")]
+    pub union_id: Option<String>,
+    /// Email address.
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Mobile country code.
+    #[serde(default, rename = "stateCode")]
+    pub state_code: Option<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OAuthUserProfile, UserAccessToken};
+
+    #[test]
+    fn user_access_token_debug_redacts_tokens() {
+        let token: UserAccessToken = serde_json::from_str(
+            r#"{"accessToken":"secret-access","refreshToken":"secret-refresh","expireIn":7200}"#,
+        )
+        .expect("token should deserialize");
+        assert_eq!(token.expire_in, Some(7200));
+
+        let debug = format!("{token:?}");
+        assert!(!debug.contains("secret-access"));
+        assert!(!debug.contains("secret-refresh"));
+    }
+
+    #[test]
+    fn user_profile_keeps_unknown_fields() {
+        let profile: OAuthUserProfile = serde_json::from_str(
+            r#"{"nick":"Zhang","unionId":"union-1","openId":"open-1","visitor":true}"#,
+        )
+        .expect("profile should deserialize");
+        assert_eq!(profile.union_id.as_deref(), Some("union-1"));
+        assert!(profile.extra.contains_key("visitor"));
+    }
+}
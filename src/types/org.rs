@@ -0,0 +1,399 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::enterprise::{ContactDepartment, ContactUser};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Department captured in an [`OrgSnapshot`].
+pub struct OrgDepartment {
+    /// Department id.
+    pub dept_id: i64,
+    /// Parent department id (`None` for the organization root).
+    #[serde(default)]
+    pub parent_id: Option<i64>,
+    /// Department name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Direct child department ids.
+    #[serde(default)]
+    pub sub_dept_ids: Vec<i64>,
+    /// Department details as returned by DingTalk.
+    pub detail: ContactDepartment,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// User captured in an [`OrgSnapshot`], deduplicated across departments.
+pub struct OrgUser {
+    /// DingTalk user id.
+    pub userid: String,
+    /// Walked departments the user belongs to, in ascending order.
+    pub dept_ids: Vec<i64>,
+    /// User details from the first department listing that returned the user.
+    pub detail: ContactUser,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// In-memory organization tree produced by an org tree walker.
+pub struct OrgSnapshot {
+    /// Department the walk started from.
+    pub root_dept_id: i64,
+    /// Departments keyed by id.
+    pub departments: BTreeMap<i64, OrgDepartment>,
+    /// Users keyed by user id.
+    pub users: BTreeMap<String, OrgUser>,
+}
+
+impl OrgSnapshot {
+    pub(crate) fn new(root_dept_id: i64) -> Self {
+        Self {
+            root_dept_id,
+            departments: BTreeMap::new(),
+            users: BTreeMap::new(),
+        }
+    }
+
+    /// Returns a department by id.
+    #[must_use]
+    pub fn department(&self, dept_id: i64) -> Option<&OrgDepartment> {
+        self.departments.get(&dept_id)
+    }
+
+    /// Returns a user by id.
+    #[must_use]
+    pub fn user(&self, userid: &str) -> Option<&OrgUser> {
+        self.users.get(userid)
+    }
+
+    /// Returns the users that belong to `dept_id`.
+    pub fn department_users(&self, dept_id: i64) -> impl Iterator<Item = &OrgUser> {
+        self.users
+            .values()
+            .filter(move |user| user.dept_ids.contains(&dept_id))
+    }
+
+    /// Compares this snapshot with a newer one and reports the changes.
+    ///
+    /// Per-department user fields (`leader`, `dept_order`) are not compared, since they
+    /// depend on which department listing returned the user first.
+    #[must_use]
+    pub fn diff(&self, newer: &OrgSnapshot) -> OrgDiff {
+        let mut diff = OrgDiff::default();
+
+        for (dept_id, department) in &newer.departments {
+            match self.departments.get(dept_id) {
+                None => diff.added_departments.push(department.clone()),
+                Some(old) if old.parent_id != department.parent_id => {
+                    diff.moved_departments.push(DepartmentMove {
+                        dept_id: *dept_id,
+                        from_parent_id: old.parent_id,
+                        to_parent_id: department.parent_id,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        diff.removed_departments = self
+            .departments
+            .iter()
+            .filter(|(dept_id, _)| !newer.departments.contains_key(dept_id))
+            .map(|(_, department)| department.clone())
+            .collect();
+
+        for (userid, user) in &newer.users {
+            match self.users.get(userid) {
+                None => diff.added_users.push(user.clone()),
+                Some(old) => {
+                    let changes = user_field_changes(old, user);
+                    if !changes.is_empty() {
+                        diff.updated_users.push(UserChange {
+                            userid: userid.clone(),
+                            changes,
+                        });
+                    }
+                }
+            }
+        }
+        diff.removed_users = self
+            .users
+            .iter()
+            .filter(|(userid, _)| !newer.users.contains_key(*userid))
+            .map(|(_, user)| user.clone())
+            .collect();
+
+        diff
+    }
+
+    /// Records one visited department and its users.
+    pub(crate) fn insert_department(
+        &mut self,
+        dept_id: i64,
+        detail: ContactDepartment,
+        sub_dept_ids: Vec<i64>,
+        users: Vec<ContactUser>,
+    ) {
+        for user in users {
+            let Some(userid) = user.userid.clone() else {
+                continue;
+            };
+            let entry = self.users.entry(userid.clone()).or_insert_with(|| OrgUser {
+                userid,
+                dept_ids: Vec::new(),
+                detail: user,
+            });
+            if let Err(index) = entry.dept_ids.binary_search(&dept_id) {
+                entry.dept_ids.insert(index, dept_id);
+            }
+        }
+
+        self.departments.insert(
+            dept_id,
+            OrgDepartment {
+                dept_id,
+                parent_id: detail.parent_id,
+                name: detail.name.clone(),
+                sub_dept_ids,
+                detail,
+            },
+        );
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Changes between two [`OrgSnapshot`]s, as returned by [`OrgSnapshot::diff`].
+pub struct OrgDiff {
+    /// Departments only present in the newer snapshot.
+    pub added_departments: Vec<OrgDepartment>,
+    /// Departments only present in the older snapshot.
+    pub removed_departments: Vec<OrgDepartment>,
+    /// Departments whose parent changed.
+    pub moved_departments: Vec<DepartmentMove>,
+    /// Users only present in the newer snapshot.
+    pub added_users: Vec<OrgUser>,
+    /// Users only present in the older snapshot.
+    pub removed_users: Vec<OrgUser>,
+    /// Users present in both snapshots whose fields changed.
+    pub updated_users: Vec<UserChange>,
+}
+
+impl OrgDiff {
+    /// Returns `true` when the snapshots are equivalent.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added_departments.is_empty()
+            && self.removed_departments.is_empty()
+            && self.moved_departments.is_empty()
+            && self.added_users.is_empty()
+            && self.removed_users.is_empty()
+            && self.updated_users.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Department moved under a different parent.
+pub struct DepartmentMove {
+    /// Department id.
+    pub dept_id: i64,
+    /// Parent in the older snapshot.
+    pub from_parent_id: Option<i64>,
+    /// Parent in the newer snapshot.
+    pub to_parent_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Field-level changes of one user.
+pub struct UserChange {
+    /// DingTalk user id.
+    pub userid: String,
+    /// Changed fields, in field name order.
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// One changed field, named by its DingTalk JSON key.
+pub struct FieldChange {
+    /// Field name (`dept_ids` for department membership).
+    pub field: String,
+    /// Value in the older snapshot (`None` when absent or null).
+    pub old: Option<Value>,
+    /// Value in the newer snapshot (`None` when absent or null).
+    pub new: Option<Value>,
+}
+
+fn user_field_changes(old: &OrgUser, new: &OrgUser) -> Vec<FieldChange> {
+    let mut old_fields = user_fields(old);
+    let mut changes = Vec::new();
+    for (field, new_value) in user_fields(new) {
+        let old_value = old_fields.remove(&field);
+        if old_value.as_ref() != Some(&new_value) {
+            changes.push(FieldChange {
+                field,
+                old: old_value,
+                new: Some(new_value),
+            });
+        }
+    }
+    changes.extend(
+        old_fields
+            .into_iter()
+            .map(|(field, old_value)| FieldChange {
+                field,
+                old: Some(old_value),
+                new: None,
+            }),
+    );
+    changes.sort_by(|left, right| left.field.cmp(&right.field));
+    changes
+}
+
+/// Fields that describe the user within the listed department, so they depend on which
+/// department listing returned the user first.
+const PER_DEPARTMENT_USER_FIELDS: &[&str] = &["dept_order", "leader"];
+
+/// Flattens a user into its JSON fields, dropping nulls so absent and null compare equal.
+fn user_fields(user: &OrgUser) -> BTreeMap<String, Value> {
+    let mut fields: BTreeMap<_, _> = match serde_json::to_value(&user.detail) {
+        Ok(Value::Object(map)) => map
+            .into_iter()
+            .filter(|(field, value)| {
+                !value.is_null() && !PER_DEPARTMENT_USER_FIELDS.contains(&field.as_str())
+            })
+            .collect(),
+        _ => BTreeMap::new(),
+    };
+    fields.insert("dept_ids".to_string(), Value::from(user.dept_ids.clone()));
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldChange, OrgSnapshot};
+
+    #[test]
+    fn snapshot_deduplicates_users_and_round_trips_json() {
+        let mut snapshot = OrgSnapshot::new(1);
+        let user = |userid: &str| {
+            serde_json::from_value(serde_json::json!({ "userid": userid })).expect("user")
+        };
+        let department = |dept_id: i64, parent_id: Option<i64>| {
+            serde_json::from_value(serde_json::json!({
+                "dept_id": dept_id,
+                "parent_id": parent_id,
+                "name": format!("dept-{dept_id}"),
+            }))
+            .expect("department")
+        };
+        snapshot.insert_department(2, department(2, Some(1)), Vec::new(), vec![user("u1")]);
+        snapshot.insert_department(
+            1,
+            department(1, None),
+            vec![2],
+            vec![user("u1"), user("u2")],
+        );
+
+        assert_eq!(snapshot.users.len(), 2);
+        assert_eq!(snapshot.user("u1").expect("u1").dept_ids, [1, 2]);
+        assert_eq!(snapshot.department(2).expect("dept").parent_id, Some(1));
+        assert_eq!(snapshot.department_users(2).count(), 1);
+
+        let json = serde_json::to_string(&snapshot).expect("serialize");
+        let restored: OrgSnapshot = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(restored.departments.len(), 2);
+        assert_eq!(restored.user("u2").expect("u2").dept_ids, [1]);
+    }
+
+    #[test]
+    fn diff_reports_department_and_user_changes() {
+        let department = |dept_id: i64, parent_id: i64| {
+            serde_json::from_value(serde_json::json!({
+                "dept_id": dept_id,
+                "parent_id": parent_id,
+            }))
+            .expect("department")
+        };
+        let user = |value: serde_json::Value| serde_json::from_value(value).expect("user");
+
+        let mut older = OrgSnapshot::new(1);
+        older.insert_department(
+            2,
+            department(2, 1),
+            Vec::new(),
+            vec![
+                user(serde_json::json!({"userid": "u1", "name": "Old", "title": "Dev"})),
+                user(serde_json::json!({"userid": "gone"})),
+            ],
+        );
+        older.insert_department(3, department(3, 1), Vec::new(), Vec::new());
+
+        let mut newer = OrgSnapshot::new(1);
+        newer.insert_department(2, department(2, 4), Vec::new(), Vec::new());
+        newer.insert_department(
+            4,
+            department(4, 1),
+            Vec::new(),
+            vec![
+                user(serde_json::json!({"userid": "u1", "name": "New", "title": null})),
+                user(serde_json::json!({"userid": "hired"})),
+            ],
+        );
+
+        let diff = older.diff(&newer);
+        assert_eq!(diff.added_departments.len(), 1);
+        assert_eq!(diff.added_departments[0].dept_id, 4);
+        assert_eq!(diff.removed_departments[0].dept_id, 3);
+        assert_eq!(diff.moved_departments[0].to_parent_id, Some(4));
+        assert_eq!(diff.added_users[0].userid, "hired");
+        assert_eq!(diff.removed_users[0].userid, "gone");
+
+        let changes = &diff.updated_users[0].changes;
+        let fields: Vec<_> = changes.iter().map(|change| change.field.as_str()).collect();
+        assert_eq!(fields, ["dept_ids", "name", "title"]);
+        assert_eq!(
+            changes[2],
+            FieldChange {
+                field: "title".to_string(),
+                old: Some("Dev".into()),
+                new: None,
+            }
+        );
+
+        assert!(newer.diff(&newer).is_empty());
+    }
+
+    #[test]
+    fn diff_ignores_per_department_fields_across_crawl_orders() {
+        let department = |dept_id: i64| {
+            serde_json::from_value(serde_json::json!({ "dept_id": dept_id, "parent_id": 1 }))
+                .expect("department")
+        };
+        let listed_in = |leader: bool, dept_order: i64| {
+            vec![
+                serde_json::from_value(serde_json::json!({
+                    "userid": "u1",
+                    "name": "Same",
+                    "leader": leader,
+                    "dept_order": dept_order,
+                }))
+                .expect("user"),
+            ]
+        };
+
+        let mut older = OrgSnapshot::new(1);
+        older.insert_department(2, department(2), Vec::new(), listed_in(true, 1));
+        older.insert_department(3, department(3), Vec::new(), listed_in(false, 5));
+
+        let mut newer = OrgSnapshot::new(1);
+        newer.insert_department(3, department(3), Vec::new(), listed_in(false, 5));
+        newer.insert_department(2, department(2), Vec::new(), listed_in(true, 1));
+
+        assert!(older.diff(&newer).is_empty());
+    }
+}
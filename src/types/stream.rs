@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::Result;
+use crate::types::callback::RobotCallbackMessage;
+
+/// Stream Mode topic for robot messages.
+pub const STREAM_TOPIC_ROBOT_MESSAGE: &str = "/v1.0/im/bot/messages/get";
+/// Stream Mode topic for interactive card callbacks.
+pub const STREAM_TOPIC_CARD_CALLBACK: &str = "/v1.0/card/instances/callback";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Stream Mode frame category.
+pub enum StreamFrameType {
+    /// Connection management frames (`ping`, `disconnect`).
+    #[serde(rename = "SYSTEM")]
+    System,
+    /// Event subscription pushes.
+    #[serde(rename = "EVENT")]
+    Event,
+    /// Robot message and card callbacks.
+    #[serde(rename = "CALLBACK")]
+    Callback,
+    /// Frame type not modeled explicitly.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Topic registered when opening a Stream Mode connection.
+pub struct StreamSubscription {
+    /// Frame category delivered for this topic.
+    #[serde(rename = "type")]
+    pub subscription_type: StreamFrameType,
+    /// Topic name, or `*` for all events.
+    pub topic: String,
+}
+
+impl StreamSubscription {
+    /// Creates a subscription for a frame category and topic.
+    #[must_use]
+    pub fn new(subscription_type: StreamFrameType, topic: impl Into<String>) -> Self {
+        Self {
+            subscription_type,
+            topic: topic.into(),
+        }
+    }
+
+    /// Subscribes to every event type enabled for the app.
+    #[must_use]
+    pub fn all_events() -> Self {
+        Self::new(StreamFrameType::Event, "*")
+    }
+
+    /// Subscribes to robot messages.
+    #[must_use]
+    pub fn robot_messages() -> Self {
+        Self::new(StreamFrameType::Callback, STREAM_TOPIC_ROBOT_MESSAGE)
+    }
+
+    /// Subscribes to interactive card callbacks.
+    #[must_use]
+    pub fn card_callbacks() -> Self {
+        Self::new(StreamFrameType::Callback, STREAM_TOPIC_CARD_CALLBACK)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Headers carried by a Stream Mode frame.
+pub struct StreamHeaders {
+    /// Frame id, echoed in the ack.
+    #[serde(default, rename = "messageId")]
+    pub message_id: Option<String>,
+    /// Frame topic (`ping`, a callback topic, or an event topic).
+    #[serde(default)]
+    pub topic: Option<String>,
+    /// Content type of `data`.
+    #[serde(default, rename = "contentType")]
+    pub content_type: Option<String>,
+    /// Connection id assigned by the gateway.
+    #[serde(default, rename = "connectionId")]
+    pub connection_id: Option<String>,
+    /// Event type for `EVENT` frames.
+    #[serde(default, rename = "eventType")]
+    pub event_type: Option<String>,
+    /// Event id for `EVENT` frames.
+    #[serde(default, rename = "eventId")]
+    pub event_id: Option<String>,
+    /// Corp id the event belongs to.
+    #[serde(default, rename = "eventCorpId")]
+    pub event_corp_id: Option<String>,
+    /// Additional headers not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Frame received over a Stream Mode connection.
+pub struct StreamFrame {
+    /// Protocol version.
+    #[serde(default, rename = "specVersion")]
+    pub spec_version: Option<String>,
+    /// Frame category.
+    #[serde(rename = "type")]
+    pub frame_type: StreamFrameType,
+    /// Frame headers.
+    #[serde(default)]
+    pub headers: StreamHeaders,
+    /// JSON-encoded payload.
+    #[serde(default)]
+    pub data: String,
+}
+
+impl StreamFrame {
+    /// Returns the frame topic.
+    #[must_use]
+    pub fn topic(&self) -> Option<&str> {
+        self.headers.topic.as_deref()
+    }
+
+    /// Deserializes the JSON payload.
+    pub fn data_json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_str(&self.data)?)
+    }
+
+    /// Deserializes the payload of a robot message callback.
+    pub fn robot_message(&self) -> Result<RobotCallbackMessage> {
+        self.data_json()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+/// Acknowledgement returned for an `EVENT` or `CALLBACK` frame.
+pub struct StreamAck {
+    /// Ack status code (`200` on success).
+    pub code: u16,
+    /// Ack status message.
+    pub message: String,
+    /// Ack payload, sent JSON-encoded.
+    pub data: Value,
+}
+
+impl StreamAck {
+    /// Creates an ack with explicit code, message, and payload.
+    #[must_use]
+    pub fn new(code: u16, message: impl Into<String>, data: Value) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data,
+        }
+    }
+
+    /// Acknowledges an event as processed.
+    #[must_use]
+    pub fn success() -> Self {
+        Self::event_status("SUCCESS", "success")
+    }
+
+    /// Asks the gateway to redeliver an event later.
+    #[must_use]
+    pub fn later() -> Self {
+        Self::event_status("LATER", "later")
+    }
+
+    /// Acknowledges a callback with a response payload.
+    #[must_use]
+    pub fn callback(response: Value) -> Self {
+        Self::new(200, "OK", serde_json::json!({ "response": response }))
+    }
+
+    fn event_status(status: &str, message: &str) -> Self {
+        Self::new(
+            200,
+            "OK",
+            serde_json::json!({ "status": status, "message": message }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StreamFrame, StreamFrameType, StreamSubscription};
+
+    #[test]
+    fn stream_frame_parses_callback_and_unknown_types() {
+        let frame: StreamFrame = serde_json::from_str(
+            r#"{"specVersion":"1.0","type":"CALLBACK","headers":{"messageId":"m1","topic":"/v1.0/im/bot/messages/get","time":"1700000000000"},"data":"{\"msgtype\":\"text\",\"text\":{\"content\":\"hi\"}}"}"#,
+        )
+        .expect("frame should deserialize");
+        assert_eq!(frame.frame_type, StreamFrameType::Callback);
+        assert_eq!(frame.headers.message_id.as_deref(), Some("m1"));
+        assert!(frame.headers.extra.contains_key("time"));
+        let message = frame.robot_message().expect("robot message");
+        assert_eq!(message.text(), Some("hi"));
+
+        let frame: StreamFrame =
+            serde_json::from_str(r#"{"type":"NEW_KIND","headers":{},"data":""}"#)
+                .expect("frame should deserialize");
+        assert_eq!(frame.frame_type, StreamFrameType::Unknown);
+
+        let subscription =
+            serde_json::to_value(StreamSubscription::robot_messages()).expect("serialize");
+        assert_eq!(subscription["type"], "CALLBACK");
+    }
+}
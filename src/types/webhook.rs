@@ -1,4 +1,9 @@
-use serde::Serialize;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::internal::{RawBodyResponse, WebhookPayload};
 
 /// Multi-button `actionCard` button item.
 #[derive(Debug, Clone, Serialize)]
@@ -49,3 +54,346 @@ impl FeedCardLink {
         }
     }
 }
+
+/// `@` mention settings for text and markdown messages.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WebhookAt {
+    /// Mobile numbers to mention.
+    #[serde(rename = "atMobiles", skip_serializing_if = "Option::is_none")]
+    pub at_mobiles: Option<Vec<String>>,
+    /// User ids to mention.
+    #[serde(rename = "atUserIds", skip_serializing_if = "Option::is_none")]
+    pub at_user_ids: Option<Vec<String>>,
+    /// Whether to mention everyone in the group.
+    #[serde(rename = "isAtAll", skip_serializing_if = "Option::is_none")]
+    pub is_at_all: Option<bool>,
+}
+
+impl WebhookAt {
+    pub(crate) fn from_parts(
+        at_mobiles: Option<Vec<String>>,
+        at_user_ids: Option<Vec<String>>,
+        is_at_all: Option<bool>,
+    ) -> Option<Self> {
+        if at_mobiles.is_some() || at_user_ids.is_some() || is_at_all.is_some() {
+            Some(Self {
+                at_mobiles,
+                at_user_ids,
+                is_at_all,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+fn collect_strings<I, S>(values: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    values.into_iter().map(Into::into).collect()
+}
+
+/// `text` webhook message.
+#[derive(Debug, Clone)]
+pub struct TextMessage {
+    /// Message content.
+    pub content: String,
+    /// Optional mention settings.
+    pub at: Option<WebhookAt>,
+}
+
+impl TextMessage {
+    /// Creates a text message.
+    #[must_use]
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            at: None,
+        }
+    }
+
+    /// Mentions users by mobile number.
+    #[must_use]
+    pub fn at_mobiles<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.at.get_or_insert_default().at_mobiles = Some(collect_strings(values));
+        self
+    }
+
+    /// Mentions users by user id.
+    #[must_use]
+    pub fn at_user_ids<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.at.get_or_insert_default().at_user_ids = Some(collect_strings(values));
+        self
+    }
+
+    /// Mentions everyone in the group.
+    #[must_use]
+    pub fn at_all(mut self) -> Self {
+        self.at.get_or_insert_default().is_at_all = Some(true);
+        self
+    }
+}
+
+/// `link` webhook message.
+#[derive(Debug, Clone)]
+pub struct LinkMessage {
+    /// Message title.
+    pub title: String,
+    /// Message text.
+    pub text: String,
+    /// Jump URL after clicking the message.
+    pub message_url: String,
+    /// Optional image URL.
+    pub pic_url: Option<String>,
+}
+
+impl LinkMessage {
+    /// Creates a link message.
+    #[must_use]
+    pub fn new(
+        title: impl Into<String>,
+        text: impl Into<String>,
+        message_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            text: text.into(),
+            message_url: message_url.into(),
+            pic_url: None,
+        }
+    }
+
+    /// Sets image URL.
+    #[must_use]
+    pub fn pic_url(mut self, value: impl Into<String>) -> Self {
+        self.pic_url = Some(value.into());
+        self
+    }
+}
+
+/// `markdown` webhook message.
+#[derive(Debug, Clone)]
+pub struct MarkdownMessage {
+    /// Message title shown in conversation previews.
+    pub title: String,
+    /// Markdown text.
+    pub text: String,
+    /// Optional mention settings.
+    pub at: Option<WebhookAt>,
+}
+
+impl MarkdownMessage {
+    /// Creates a markdown message.
+    #[must_use]
+    pub fn new(title: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            text: text.into(),
+            at: None,
+        }
+    }
+
+    /// Mentions users by mobile number.
+    #[must_use]
+    pub fn at_mobiles<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.at.get_or_insert_default().at_mobiles = Some(collect_strings(values));
+        self
+    }
+
+    /// Mentions users by user id.
+    #[must_use]
+    pub fn at_user_ids<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.at.get_or_insert_default().at_user_ids = Some(collect_strings(values));
+        self
+    }
+
+    /// Mentions everyone in the group.
+    #[must_use]
+    pub fn at_all(mut self) -> Self {
+        self.at.get_or_insert_default().is_at_all = Some(true);
+        self
+    }
+}
+
+/// `actionCard` webhook message with either one or multiple buttons.
+#[derive(Debug, Clone)]
+pub struct ActionCardMessage {
+    /// Card title.
+    pub title: String,
+    /// Card markdown text.
+    pub text: String,
+    /// Optional button orientation (`"0"` vertical, `"1"` horizontal).
+    pub btn_orientation: Option<String>,
+    /// Single-button title.
+    pub single_title: Option<String>,
+    /// Single-button jump URL.
+    pub single_url: Option<String>,
+    /// Multi-button items.
+    pub btns: Option<Vec<ActionCardButton>>,
+}
+
+impl ActionCardMessage {
+    /// Creates a single-button action card.
+    #[must_use]
+    pub fn single(
+        title: impl Into<String>,
+        text: impl Into<String>,
+        single_title: impl Into<String>,
+        single_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            text: text.into(),
+            btn_orientation: None,
+            single_title: Some(single_title.into()),
+            single_url: Some(single_url.into()),
+            btns: None,
+        }
+    }
+
+    /// Creates a multi-button action card.
+    #[must_use]
+    pub fn multi(
+        title: impl Into<String>,
+        text: impl Into<String>,
+        btns: Vec<ActionCardButton>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            text: text.into(),
+            btn_orientation: None,
+            single_title: None,
+            single_url: None,
+            btns: Some(btns),
+        }
+    }
+
+    /// Sets button orientation.
+    #[must_use]
+    pub fn btn_orientation(mut self, value: impl Into<String>) -> Self {
+        self.btn_orientation = Some(value.into());
+        self
+    }
+}
+
+/// `feedCard` webhook message.
+#[derive(Debug, Clone)]
+pub struct FeedCardMessage {
+    /// Feed-card links.
+    pub links: Vec<FeedCardLink>,
+}
+
+impl FeedCardMessage {
+    /// Creates a feed-card message.
+    #[must_use]
+    pub fn new(links: Vec<FeedCardLink>) -> Self {
+        Self { links }
+    }
+
+    /// Appends a feed-card link.
+    #[must_use]
+    pub fn link(mut self, value: FeedCardLink) -> Self {
+        self.links.push(value);
+        self
+    }
+}
+
+/// Webhook robot message.
+///
+/// Serializes to the JSON body expected by `robot/send`, so the same value can
+/// be sent from async and blocking services.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum WebhookMessage {
+    /// `text` message.
+    Text(TextMessage),
+    /// `link` message.
+    Link(LinkMessage),
+    /// `markdown` message.
+    Markdown(MarkdownMessage),
+    /// `actionCard` message.
+    ActionCard(ActionCardMessage),
+    /// `feedCard` message.
+    FeedCard(FeedCardMessage),
+}
+
+impl Serialize for WebhookMessage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        WebhookPayload::from(self).serialize(serializer)
+    }
+}
+
+impl From<TextMessage> for WebhookMessage {
+    fn from(value: TextMessage) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<LinkMessage> for WebhookMessage {
+    fn from(value: LinkMessage) -> Self {
+        Self::Link(value)
+    }
+}
+
+impl From<MarkdownMessage> for WebhookMessage {
+    fn from(value: MarkdownMessage) -> Self {
+        Self::Markdown(value)
+    }
+}
+
+impl From<ActionCardMessage> for WebhookMessage {
+    fn from(value: ActionCardMessage) -> Self {
+        Self::ActionCard(value)
+    }
+}
+
+impl From<FeedCardMessage> for WebhookMessage {
+    fn from(value: FeedCardMessage) -> Self {
+        Self::FeedCard(value)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Response payload for webhook robot sends.
+pub struct WebhookSendResponse {
+    /// DingTalk error code (`0` on success).
+    #[serde(default)]
+    pub errcode: Option<i64>,
+    /// DingTalk error message.
+    #[serde(default, alias = "message")]
+    pub errmsg: Option<String>,
+    /// Additional response fields not modeled explicitly.
+    #[serde(flatten, default)]
+    pub extra: BTreeMap<String, Value>,
+    /// Raw response body, kept for debugging.
+    #[serde(skip)]
+    pub raw_body: String,
+}
+
+impl RawBodyResponse for WebhookSendResponse {
+    fn set_raw_body(&mut self, body: String) {
+        self.raw_body = body;
+    }
+}
@@ -1,6 +1,18 @@
 #![cfg(feature = "_async")]
 
-use dingtalk_sdk::{Client, ContactGetUserRequest, ErrorKind};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use dingtalk_sdk::{
+    AccessTokenStore, AppCredentials, CachedAccessToken, Client, ContactCountUsersRequest,
+    ContactCreateExternalContactRequest, ContactGetAdminScopeRequest,
+    ContactGetExternalContactRequest, ContactGetUserRequest, ContactListEmpLeaveRecordsRequest,
+    ContactListExternalContactsRequest, ContactListUserIdsRequest, ErrorKind,
+    InMemoryAccessTokenStore, InMemorySuiteTicketStore, MarkdownMessage, ReplyMessageResponse,
+    RobotCallbackMessage, RoleAssignmentRequest, RoleGetRoleGroupRequest, RoleGetRoleRequest,
+    SuiteCredentials, TextMessage, TokenEndpoint,
+};
+use futures_util::TryStreamExt;
 use httpmock::prelude::*;
 
 #[tokio::test]
@@ -95,3 +107,1172 @@ async fn async_webhook_error_keeps_snippet_out_of_display() {
 
     send.assert_async().await;
 }
+
+#[tokio::test]
+async fn async_webhook_send_posts_typed_message() {
+    let server = MockServer::start_async().await;
+
+    let send = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/robot/send")
+                .query_param("access_token", "token-123")
+                .body_includes("\"msgtype\":\"markdown\"")
+                .body_includes("\"atUserIds\":[\"user-1\"]");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok"}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let webhook = client.webhook("token-123", None);
+
+    let message = MarkdownMessage::new("Deploy", "**done**")
+        .at_user_ids(["user-1"])
+        .into();
+    let response = webhook
+        .send(&message)
+        .await
+        .expect("request should succeed");
+
+    assert_eq!(response.errcode, Some(0));
+    assert_eq!(response.raw_body, r#"{"errcode":0,"errmsg":"ok"}"#);
+
+    send.assert_async().await;
+}
+
+#[tokio::test]
+async fn async_reply_callback_message_routes_group_conversation() {
+    let server = MockServer::start_async().await;
+
+    let get_token = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{"errcode":0,"errmsg":"ok","access_token":"token-123","expires_in":7200}"#,
+                );
+        })
+        .await;
+
+    let send = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/v1.0/robot/groupMessages/send")
+                .header("x-acs-dingtalk-access-token", "token-123")
+                .body_includes("\"openConversationId\":\"cid-1\"");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"processQueryKey":"query-key-1"}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let enterprise = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let callback: RobotCallbackMessage = serde_json::from_str(
+        r#"{"conversationId":"cid-1","conversationType":"2","msgtype":"text","text":{"content":"ping"}}"#,
+    )
+    .expect("callback should deserialize");
+
+    let response = enterprise
+        .reply_callback_message(&callback, "Reply", "pong")
+        .await
+        .expect("request should succeed");
+
+    assert!(matches!(response, ReplyMessageResponse::Group(_)));
+    assert_eq!(response.process_query_key(), Some("query-key-1"));
+
+    get_token.assert_async().await;
+    send.assert_async().await;
+}
+
+#[tokio::test]
+async fn async_session_webhook_posts_to_callback_session_url() {
+    let server = MockServer::start_async().await;
+
+    let send = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/robot/sendBySession")
+                .query_param("session", "session-1")
+                .body_includes("\"content\":\"pong\"");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok"}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+
+    let callback: RobotCallbackMessage = serde_json::from_value(serde_json::json!({
+        "msgtype": "text",
+        "text": {"content": "ping"},
+        "sessionWebhook": server.url("/robot/sendBySession?session=session-1"),
+        "sessionWebhookExpiredTime": i64::MAX,
+    }))
+    .expect("callback should deserialize");
+
+    let session = client
+        .session_webhook_from_callback(&callback)
+        .expect("callback should carry a session webhook");
+    let response = session
+        .send(&TextMessage::new("pong").into())
+        .await
+        .expect("request should succeed");
+
+    assert_eq!(response.errcode, Some(0));
+    send.assert_async().await;
+}
+
+#[tokio::test]
+async fn async_clients_share_access_token_store() {
+    let server = MockServer::start_async().await;
+
+    let get_token = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{"errcode":0,"errmsg":"ok","access_token":"token-123","expires_in":7200}"#,
+                );
+        })
+        .await;
+
+    let store = Arc::new(InMemoryAccessTokenStore::new());
+    let build_client = || {
+        Client::builder()
+            .webhook_base_url(server.base_url())
+            .enterprise_base_url(server.base_url())
+            .access_token_store(Arc::clone(&store))
+            .build()
+            .expect("client should build")
+    };
+
+    let first = build_client()
+        .enterprise("app-key", "app-secret", "robot-code")
+        .get_access_token()
+        .await
+        .expect("token should be fetched");
+    let second = build_client()
+        .enterprise("app-key", "app-secret", "robot-code")
+        .get_access_token()
+        .await
+        .expect("token should come from the shared store");
+
+    assert_eq!(first, "token-123");
+    assert_eq!(second, "token-123");
+    assert_eq!(
+        store.get("app-key").map(|cached| cached.token).as_deref(),
+        Some("token-123")
+    );
+    get_token.assert_calls_async(1).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn async_concurrent_token_refreshes_are_coalesced() {
+    let server = MockServer::start_async().await;
+
+    let get_token = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .delay(std::time::Duration::from_millis(100))
+                .body(
+                    r#"{"errcode":0,"errmsg":"ok","access_token":"token-123","expires_in":7200}"#,
+                );
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+
+    let tasks: Vec<_> = (0..16)
+        .map(|_| {
+            let enterprise = client.enterprise("app-key", "app-secret", "robot-code");
+            tokio::spawn(async move { enterprise.get_access_token().await })
+        })
+        .collect();
+    for task in tasks {
+        let token = task
+            .await
+            .expect("task should finish")
+            .expect("token should be fetched");
+        assert_eq!(token, "token-123");
+    }
+
+    get_token.assert_calls_async(1).await;
+}
+
+#[tokio::test]
+async fn async_concurrent_token_refreshes_share_result_without_cache() {
+    let server = MockServer::start_async().await;
+
+    let get_token = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .delay(std::time::Duration::from_millis(100))
+                .body(
+                    r#"{"errcode":0,"errmsg":"ok","access_token":"token-123","expires_in":7200}"#,
+                );
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .cache_access_token(false)
+        .build()
+        .expect("client should build");
+
+    let tasks: Vec<_> = (0..16)
+        .map(|_| {
+            let enterprise = client.enterprise("app-key", "app-secret", "robot-code");
+            tokio::spawn(async move { enterprise.get_access_token().await })
+        })
+        .collect();
+    for task in tasks {
+        let token = task
+            .await
+            .expect("task should finish")
+            .expect("token should be fetched");
+        assert_eq!(token, "token-123");
+    }
+    get_token.assert_calls_async(1).await;
+
+    client
+        .enterprise("app-key", "app-secret", "robot-code")
+        .get_access_token()
+        .await
+        .expect("token should be fetched again");
+    get_token.assert_calls_async(2).await;
+}
+
+#[tokio::test]
+async fn async_concurrent_token_refresh_failure_is_shared() {
+    let server = MockServer::start_async().await;
+
+    let get_token = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .delay(std::time::Duration::from_millis(100))
+                .body(r#"{"errcode":40089,"errmsg":"invalid appkey"}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+
+    let tasks: Vec<_> = (0..16)
+        .map(|_| {
+            let enterprise = client.enterprise("app-key", "app-secret", "robot-code");
+            tokio::spawn(async move { enterprise.get_access_token().await })
+        })
+        .collect();
+    for task in tasks {
+        let error = task
+            .await
+            .expect("task should finish")
+            .expect_err("token refresh should fail");
+        assert_eq!(error.kind(), ErrorKind::Api);
+    }
+    get_token.assert_calls_async(1).await;
+
+    client
+        .enterprise("app-key", "app-secret", "robot-code")
+        .get_access_token()
+        .await
+        .expect_err("failed refresh should not be reused");
+    get_token.assert_calls_async(2).await;
+}
+
+#[tokio::test]
+async fn async_invalid_token_is_evicted_and_request_retried_once() {
+    let server = MockServer::start_async().await;
+
+    let get_token = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{"errcode":0,"errmsg":"ok","access_token":"fresh-token","expires_in":7200}"#,
+                );
+        })
+        .await;
+    let stale = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/v2/user/get")
+                .query_param("access_token", "stale-token");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":40014,"errmsg":"invalid access_token"}"#);
+        })
+        .await;
+    let fresh = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/v2/user/get")
+                .query_param("access_token", "fresh-token");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","result":{"userid":"manager-1"}}"#);
+        })
+        .await;
+
+    let store = InMemoryAccessTokenStore::new();
+    store.store(
+        "app-key",
+        CachedAccessToken::new(
+            "stale-token",
+            SystemTime::now() + Duration::from_secs(3_600),
+        ),
+    );
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .access_token_store(store.clone())
+        .build()
+        .expect("client should build");
+
+    let user = client
+        .enterprise("app-key", "app-secret", "robot-code")
+        .contact_get_user(ContactGetUserRequest::new("manager-1"))
+        .await
+        .expect("request should succeed after refresh");
+
+    assert_eq!(user.userid.as_deref(), Some("manager-1"));
+    assert_eq!(
+        store.get("app-key").map(|cached| cached.token).as_deref(),
+        Some("fresh-token")
+    );
+    stale.assert_calls_async(1).await;
+    fresh.assert_calls_async(1).await;
+    get_token.assert_calls_async(1).await;
+}
+
+#[tokio::test]
+async fn async_oauth2_token_endpoint_keeps_secret_out_of_url() {
+    let server = MockServer::start_async().await;
+
+    let legacy = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(500);
+        })
+        .await;
+    let oauth2 = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/v1.0/oauth2/accessToken")
+                .json_body(serde_json::json!({"appKey": "app-key", "appSecret": "app-secret"}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"accessToken":"oauth-token","expireIn":7200}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .token_endpoint(TokenEndpoint::OAuth2)
+        .build()
+        .expect("client should build");
+
+    let token = client
+        .enterprise("app-key", "app-secret", "robot-code")
+        .get_access_token()
+        .await
+        .expect("token should be fetched");
+
+    assert_eq!(token, "oauth-token");
+    oauth2.assert_async().await;
+    legacy.assert_calls_async(0).await;
+}
+
+#[tokio::test]
+async fn async_corp_enterprise_caches_tokens_per_corp() {
+    let server = MockServer::start_async().await;
+
+    let corp_token = |corp_id: &'static str| {
+        let server = &server;
+        async move {
+            server
+                .mock_async(|when, then| {
+                    when.method(POST)
+                        .path("/v1.0/oauth2/corpAccessToken")
+                        .json_body(serde_json::json!({
+                            "suiteKey": "suite-key",
+                            "suiteSecret": "suite-secret",
+                            "authCorpId": corp_id,
+                            "suiteTicket": "ticket-1",
+                        }));
+                    then.status(200)
+                        .header("content-type", "application/json")
+                        .json_body(serde_json::json!({
+                            "accessToken": format!("token-{corp_id}"),
+                            "expireIn": 7200,
+                        }));
+                })
+                .await
+        }
+    };
+    let corp_a = corp_token("corp-a").await;
+    let corp_b = corp_token("corp-b").await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let tickets = InMemorySuiteTicketStore::new();
+    tickets.set("suite-key", "ticket-1");
+    let suite = SuiteCredentials::new("suite-key", "suite-secret", tickets);
+
+    let service_a = client.corp_enterprise(suite.clone(), "corp-a", "robot-code");
+    let service_b = client.corp_enterprise(suite, "corp-b", "robot-code");
+    for _ in 0..2 {
+        assert_eq!(
+            service_a.get_access_token().await.expect("corp-a token"),
+            "token-corp-a"
+        );
+    }
+    assert_eq!(
+        service_b.get_access_token().await.expect("corp-b token"),
+        "token-corp-b"
+    );
+
+    corp_a.assert_calls_async(1).await;
+    corp_b.assert_calls_async(1).await;
+}
+
+#[tokio::test]
+async fn async_oauth_exchanges_auth_code_and_fetches_profile() {
+    let server = MockServer::start_async().await;
+
+    let exchange = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/v1.0/oauth2/userAccessToken")
+                .json_body(serde_json::json!({
+                    "clientId": "app-key",
+                    "clientSecret": "app-secret",
+                    "code": "auth-code",
+                    "grantType": "authorization_code",
+                }));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"accessToken":"user-token","refreshToken":"refresh-1","expireIn":7200,"corpId":"ding-corp"}"#);
+        })
+        .await;
+    let me = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/v1.0/contact/users/me")
+                .header("x-acs-dingtalk-access-token", "user-token");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"nick":"Zhang San","unionId":"union-1","openId":"open-1","avatarUrl":"https://img.example.com/a.png"}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let oauth = client.oauth(AppCredentials::new("app-key", "app-secret"));
+
+    let token = oauth
+        .get_user_access_token("auth-code")
+        .await
+        .expect("auth code should be exchanged");
+    assert_eq!(token.refresh_token.as_deref(), Some("refresh-1"));
+    assert_eq!(token.corp_id.as_deref(), Some("ding-corp"));
+
+    let profile = oauth
+        .get_current_user(&token.access_token)
+        .await
+        .expect("profile should be fetched");
+    assert_eq!(profile.nick.as_deref(), Some("Zhang San"));
+    assert_eq!(profile.union_id.as_deref(), Some("union-1"));
+
+    exchange.assert_async().await;
+    me.assert_async().await;
+}
+
+#[tokio::test]
+async fn async_free_login_code_and_jsapi_ticket() {
+    let server = MockServer::start_async().await;
+
+    let get_token = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+        })
+        .await;
+    let user_info = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/v2/user/getuserinfo")
+                .query_param("access_token", "token-1")
+                .json_body(serde_json::json!({"code": "free-login-code"}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","result":{"userid":"user-1","unionid":"union-1","name":"Zhang San","sys":true,"sys_level":1,"device_id":"device-1"}}"#);
+        })
+        .await;
+    let ticket = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/get_jsapi_ticket")
+                .query_param("access_token", "token-1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","ticket":"jsapi-ticket","expires_in":7200}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let identity = service
+        .get_user_info_by_code("free-login-code")
+        .await
+        .expect("code should resolve");
+    assert_eq!(identity.userid, "user-1");
+    assert!(identity.sys);
+    assert_eq!(identity.sys_level, Some(1));
+    assert_eq!(identity.device_id.as_deref(), Some("device-1"));
+
+    let jsapi = service
+        .get_jsapi_ticket()
+        .await
+        .expect("ticket should be fetched");
+    assert_eq!(jsapi.ticket, "jsapi-ticket");
+    assert_eq!(jsapi.expires_in, Some(7200));
+
+    get_token.assert_calls_async(1).await;
+    user_info.assert_async().await;
+    ticket.assert_async().await;
+}
+
+#[tokio::test]
+async fn async_contact_user_listing_and_admin_queries() {
+    let server = MockServer::start_async().await;
+
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+        })
+        .await;
+    let list_simple = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/user/listsimple")
+                .query_param("access_token", "token-1")
+                .json_body(serde_json::json!({"dept_id": 1, "cursor": 0, "size": 50}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","result":{"has_more":false,"list":[{"userid":"user-1","name":"Zhang San"}]}}"#);
+        })
+        .await;
+    let list_ids = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/user/listid")
+                .json_body(serde_json::json!({"dept_id": 1}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{"errcode":0,"errmsg":"ok","result":{"userid_list":["user-1","user-2"]}}"#,
+                );
+        })
+        .await;
+    let count = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/user/count")
+                .json_body(serde_json::json!({"only_active": true}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","result":{"count":42}}"#);
+        })
+        .await;
+    let list_admins = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/user/listadmin")
+                .json_body(serde_json::json!({}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","result":[{"userid":"admin-1","sys_level":1},{"userid":"admin-2","sys_level":2}]}"#);
+        })
+        .await;
+    let admin_scope = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/user/get_admin_scope")
+                .json_body(serde_json::json!({"userid": "admin-2"}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","dept_ids":[3,4]}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let users: Vec<_> = service
+        .contact_list_simple_users_stream(1, 50)
+        .try_collect()
+        .await
+        .expect("simple users should list");
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].userid, "user-1");
+    assert_eq!(users[0].name.as_deref(), Some("Zhang San"));
+
+    let ids = service
+        .contact_list_user_ids(ContactListUserIdsRequest::new(1))
+        .await
+        .expect("user ids should list");
+    assert_eq!(ids.userid_list, ["user-1", "user-2"]);
+
+    let total = service
+        .contact_count_users(ContactCountUsersRequest::new(true))
+        .await
+        .expect("users should count");
+    assert_eq!(total.count, 42);
+
+    let admins = service
+        .contact_list_admins()
+        .await
+        .expect("admins should list");
+    assert_eq!(admins.len(), 2);
+    assert_eq!(admins[1].sys_level, Some(2));
+
+    let scope = service
+        .contact_get_admin_scope(ContactGetAdminScopeRequest::new("admin-2"))
+        .await
+        .expect("admin scope should load");
+    assert_eq!(scope.dept_ids, [3, 4]);
+
+    list_simple.assert_async().await;
+    list_ids.assert_async().await;
+    count.assert_async().await;
+    list_admins.assert_async().await;
+    admin_scope.assert_async().await;
+}
+
+#[tokio::test]
+async fn async_user_department_paths_resolve_each_department_once() {
+    let server = MockServer::start_async().await;
+
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+        })
+        .await;
+    let parents = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/v2/department/listparentbyuser")
+                .json_body(serde_json::json!({"userid": "user-1"}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","result":{"parent_list":[{"parent_dept_id_list":[3,2,1]},{"parent_dept_id_list":[4,1]}]}}"#);
+        })
+        .await;
+    let mut departments = Vec::new();
+    for dept_id in 1..=4 {
+        departments.push(
+            server
+                .mock_async(|when, then| {
+                    when.method(POST)
+                        .path("/topapi/v2/department/get")
+                        .json_body(serde_json::json!({ "dept_id": dept_id }));
+                    then.status(200)
+                        .header("content-type", "application/json")
+                        .json_body(serde_json::json!({
+                            "errcode": 0,
+                            "errmsg": "ok",
+                            "result": {"dept_id": dept_id, "name": format!("dept-{dept_id}")},
+                        }));
+                })
+                .await,
+        );
+    }
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let paths = service
+        .contact_user_department_paths("user-1")
+        .await
+        .expect("paths should resolve");
+    let names: Vec<Vec<_>> = paths
+        .iter()
+        .map(|path| {
+            path.iter()
+                .map(|department| department.name.as_deref().unwrap_or_default())
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        names,
+        [vec!["dept-3", "dept-2", "dept-1"], vec!["dept-4", "dept-1"]]
+    );
+
+    parents.assert_async().await;
+    for department in departments {
+        department.assert_calls_async(1).await;
+    }
+}
+
+#[tokio::test]
+async fn async_role_listing_lookup_and_assignment() {
+    let server = MockServer::start_async().await;
+
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+        })
+        .await;
+    let first_page = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/role/list")
+                .json_body(serde_json::json!({"offset": 0, "size": 1}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","result":{"hasMore":true,"list":[{"groupId":1,"name":"Default","roles":[{"id":10,"name":"Admin"}]}]}}"#);
+        })
+        .await;
+    let second_page = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/role/list")
+                .json_body(serde_json::json!({"offset": 1, "size": 1}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","result":{"hasMore":false,"list":[{"groupId":2,"name":"Sales","roles":[]}]}}"#);
+        })
+        .await;
+    let role_group = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/role/getrolegroup")
+                .json_body(serde_json::json!({"group_id": 1}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","role_group":{"role_list":[{"role_id":10,"role_name":"Admin"}],"group_name":"Default"}}"#);
+        })
+        .await;
+    let role = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/role/getrole")
+                .json_body(serde_json::json!({"roleId": 10}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","role":{"name":"Admin","groupId":1}}"#);
+        })
+        .await;
+    let assign = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/role/addrolesforemps")
+                .json_body(serde_json::json!({"roleIds": "10,11", "userIds": "user-1"}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok"}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let groups: Vec<_> = service
+        .role_list_stream(1)
+        .try_collect()
+        .await
+        .expect("role groups should list");
+    let group_ids: Vec<_> = groups.iter().map(|group| group.group_id).collect();
+    assert_eq!(group_ids, [1, 2]);
+    assert_eq!(groups[0].roles[0].name.as_deref(), Some("Admin"));
+
+    let group = service
+        .role_get_role_group(RoleGetRoleGroupRequest::new(1))
+        .await
+        .expect("role group should load");
+    assert_eq!(group.group_id, 1);
+    assert_eq!(group.name.as_deref(), Some("Default"));
+    assert_eq!(group.roles[0].id, 10);
+
+    let admin = service
+        .role_get_role(RoleGetRoleRequest::new(10))
+        .await
+        .expect("role should load");
+    assert_eq!(admin.id, 10);
+    assert_eq!(admin.group_id, Some(1));
+
+    service
+        .role_add_roles_for_users(RoleAssignmentRequest::new([10, 11], ["user-1"]))
+        .await
+        .expect("roles should be added");
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    role_group.assert_async().await;
+    role.assert_async().await;
+    assign.assert_async().await;
+}
+
+#[tokio::test]
+async fn async_external_contacts_create_list_and_get() {
+    let server = MockServer::start_async().await;
+
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+        })
+        .await;
+    let create = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/extcontact/create")
+                .query_param("access_token", "token-1")
+                .json_body(serde_json::json!({
+                    "contact": {
+                        "name": "Partner",
+                        "follower_user_id": "user-1",
+                        "mobile": "13800000000",
+                        "label_ids": [100],
+                        "company_name": "ACME",
+                        "share_user_ids": ["user-2"],
+                    }
+                }));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","userid":"ext-1"}"#);
+        })
+        .await;
+    let list = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/extcontact/list")
+                .json_body(serde_json::json!({"offset": 0, "size": 20}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","results":[{"userid":"ext-1","name":"Partner","label_ids":[100],"customer_level":"A"}]}"#);
+        })
+        .await;
+    let get = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/extcontact/get")
+                .json_body(serde_json::json!({"user_id": "ext-1"}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","result":{"userid":"ext-1","name":"Partner","company_name":"ACME","follower_user_id":"user-1","share_user_ids":["user-2"]}}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let userid = service
+        .contact_create_external_contact(
+            ContactCreateExternalContactRequest::new("Partner", "user-1", "13800000000", [100])
+                .company_name("ACME")
+                .share_user_ids(["user-2"]),
+        )
+        .await
+        .expect("external contact should be created");
+    assert_eq!(userid, "ext-1");
+
+    let contacts = service
+        .contact_list_external_contacts(ContactListExternalContactsRequest::new(0, 20))
+        .await
+        .expect("external contacts should list");
+    assert_eq!(contacts[0].label_ids, [100]);
+    assert_eq!(contacts[0].extra.get("customer_level"), Some(&"A".into()));
+
+    let contact = service
+        .contact_get_external_contact(ContactGetExternalContactRequest::new("ext-1"))
+        .await
+        .expect("external contact should load");
+    assert_eq!(contact.company_name.as_deref(), Some("ACME"));
+    assert_eq!(contact.share_user_ids, ["user-2"]);
+
+    create.assert_async().await;
+    list.assert_async().await;
+    get.assert_async().await;
+}
+
+#[tokio::test]
+async fn async_emp_leave_records_stream_follows_next_token() {
+    let server = MockServer::start_async().await;
+
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+        })
+        .await;
+    let first_page = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/v1.0/contact/empLeaveRecords")
+                .header("x-acs-dingtalk-access-token", "token-1")
+                .query_param("startTime", "2024-01-01T00:00:00Z")
+                .query_param("endTime", "2024-02-01T00:00:00Z")
+                .query_param("maxResults", "1")
+                .query_param_missing("nextToken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"nextToken":"1","records":[{"userId":"user-1","name":"Zhang San","leaveTime":"2024-01-05T10:00Z","leaveReason":"oapi"}]}"#);
+        })
+        .await;
+    let second_page = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/v1.0/contact/empLeaveRecords")
+                .query_param("nextToken", "1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"records":[{"userId":"user-2","leaveTime":"2024-01-20T10:00Z"}]}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let records: Vec<_> = service
+        .contact_list_emp_leave_records_stream(
+            ContactListEmpLeaveRecordsRequest::new("2024-01-01T00:00:00Z", 1)
+                .end_time("2024-02-01T00:00:00Z"),
+        )
+        .try_collect()
+        .await
+        .expect("leave records should list");
+    let userids: Vec<_> = records
+        .iter()
+        .map(|record| record.user_id.as_str())
+        .collect();
+    assert_eq!(userids, ["user-1", "user-2"]);
+    assert_eq!(records[0].leave_time.as_deref(), Some("2024-01-05T10:00Z"));
+    assert_eq!(records[0].leave_reason.as_deref(), Some("oapi"));
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+}
+
+#[tokio::test]
+async fn async_contact_list_users_stream_follows_cursor() {
+    let server = MockServer::start_async().await;
+
+    let _get_token = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+        })
+        .await;
+    let first_page = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/v2/user/list")
+                .json_body(serde_json::json!({"dept_id": 1, "cursor": 0, "size": 2}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","result":{"has_more":true,"next_cursor":2,"list":[{"userid":"u1"},{"userid":"u2"}]}}"#);
+        })
+        .await;
+    let last_page = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/topapi/v2/user/list")
+                .json_body(serde_json::json!({"dept_id": 1, "cursor": 2, "size": 2}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","result":{"has_more":false,"list":[{"userid":"u3"}]}}"#);
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let users: Vec<_> = service
+        .contact_list_users_stream(1, 2)
+        .try_collect()
+        .await
+        .expect("all pages should load");
+
+    let userids: Vec<_> = users
+        .iter()
+        .map(|user| user.userid.as_deref().unwrap_or_default())
+        .collect();
+    assert_eq!(userids, ["u1", "u2", "u3"]);
+    first_page.assert_async().await;
+    last_page.assert_async().await;
+}
+
+fn mock_org_department(
+    server: &MockServer,
+    dept_id: i64,
+    parent_id: Option<i64>,
+    sub_dept_ids: &[i64],
+    userids: &[&str],
+) {
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/v2/department/get")
+            .json_body(serde_json::json!({ "dept_id": dept_id }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "errcode": 0,
+                "errmsg": "ok",
+                "result": {"dept_id": dept_id, "parent_id": parent_id, "name": format!("dept-{dept_id}")},
+            }));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/v2/department/listsubid")
+            .json_body(serde_json::json!({ "dept_id": dept_id }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "errcode": 0,
+                "errmsg": "ok",
+                "result": {"dept_id_list": sub_dept_ids},
+            }));
+    });
+    let list: Vec<_> = userids
+        .iter()
+        .map(|userid| serde_json::json!({ "userid": userid }))
+        .collect();
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/v2/user/list")
+            .body_includes(format!("\"dept_id\":{dept_id},"));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "errcode": 0,
+                "errmsg": "ok",
+                "result": {"has_more": false, "list": list},
+            }));
+    });
+}
+
+#[tokio::test]
+async fn async_org_tree_walker_snapshots_subtree() {
+    let server = MockServer::start_async().await;
+    let get_token = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/gettoken");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+        })
+        .await;
+    mock_org_department(&server, 1, None, &[2, 3], &["boss"]);
+    mock_org_department(&server, 2, Some(1), &[4], &["u1"]);
+    mock_org_department(&server, 3, Some(1), &[], &["u1", "u2"]);
+    mock_org_department(&server, 4, Some(2), &[], &[]);
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let snapshot = client
+        .enterprise("app-key", "app-secret", "robot-code")
+        .org_tree_walker(1)
+        .concurrency(2)
+        .walk()
+        .await
+        .expect("walk should succeed");
+
+    assert_eq!(snapshot.departments.len(), 4);
+    assert_eq!(snapshot.department(1).expect("root").sub_dept_ids, [2, 3]);
+    assert_eq!(snapshot.department(4).expect("leaf").parent_id, Some(2));
+    assert_eq!(snapshot.users.len(), 3);
+    assert_eq!(snapshot.user("u1").expect("u1").dept_ids, [2, 3]);
+    assert!(serde_json::to_value(&snapshot).expect("json")["users"]["u2"].is_object());
+    get_token.assert_calls_async(1).await;
+}
@@ -1,6 +1,14 @@
 #![cfg(feature = "_blocking")]
 
-use dingtalk_sdk::{BlockingClient, ContactGetUserRequest, ErrorKind};
+use std::time::{Duration, SystemTime};
+
+use dingtalk_sdk::{
+    AccessTokenStore, AppCredentials, ApprovalListProcessInstanceIdsRequest, BlockingClient,
+    CachedAccessToken, ContactDeleteExternalContactRequest, ContactGetAdminScopeRequest,
+    ContactGetUserRequest, ContactListExternalLabelGroupsRequest, ContactListInactiveUsersRequest,
+    ContactUpdateExternalContactRequest, ErrorKind, InMemoryAccessTokenStore, MarkdownMessage,
+    RoleAssignmentRequest, SuiteCredentials, TextMessage, WebhookSendResponse,
+};
 use httpmock::prelude::*;
 
 #[test]
@@ -85,3 +93,782 @@ fn blocking_webhook_error_keeps_snippet_out_of_display() {
 
     send.assert();
 }
+
+#[test]
+fn blocking_webhook_send_posts_typed_message() {
+    let server = MockServer::start();
+
+    let send = server.mock(|when, then| {
+        when.method(POST)
+            .path("/robot/send")
+            .query_param("access_token", "token-123")
+            .body_includes("\"msgtype\":\"markdown\"")
+            .body_includes("\"atUserIds\":[\"user-1\"]");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok"}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let webhook = client.webhook("token-123", None);
+
+    let message = MarkdownMessage::new("Deploy", "**done**")
+        .at_user_ids(["user-1"])
+        .into();
+    let response = webhook.send(&message).expect("request should succeed");
+
+    assert_eq!(response.errcode, Some(0));
+    assert_eq!(response.raw_body, r#"{"errcode":0,"errmsg":"ok"}"#);
+
+    send.assert();
+}
+
+#[test]
+fn blocking_webhook_non_json_success_body_is_not_an_error() {
+    let server = MockServer::start();
+
+    let send = server.mock(|when, then| {
+        when.method(POST)
+            .path("/robot/send")
+            .query_param("access_token", "token-123");
+        then.status(200)
+            .header("content-type", "text/plain")
+            .body("ok");
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let webhook = client.webhook("token-123", None);
+
+    let response: WebhookSendResponse = webhook
+        .send_text_message("hello", None, None, Some(false))
+        .expect("accepted message should not fail");
+    assert_eq!(response.raw_body, "ok");
+    assert_eq!(response.errcode, None);
+
+    send.assert_calls(1);
+}
+
+#[test]
+fn blocking_oto_message_returns_typed_response() {
+    let server = MockServer::start();
+
+    let get_token = server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-123","expires_in":7200}"#);
+    });
+
+    let send = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1.0/robot/oToMessages/batchSend")
+            .header("x-acs-dingtalk-access-token", "token-123")
+            .body_includes("\"userIds\":[\"user-1\"]");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+                "processQueryKey":"query-key-1",
+                "invalidStaffIdList":["user-2"],
+                "flowControlledStaffIdList":[]
+            }"#,
+            );
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let enterprise = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let response = enterprise
+        .send_oto_message("user-1", "Title", "Body")
+        .expect("request should succeed");
+
+    assert_eq!(response.process_query_key.as_deref(), Some("query-key-1"));
+    assert_eq!(response.invalid_staff_id_list, vec!["user-2".to_string()]);
+    assert!(response.flow_controlled_staff_id_list.is_empty());
+    assert!(response.raw_body.contains("query-key-1"));
+
+    get_token.assert();
+    send.assert();
+}
+
+#[test]
+fn blocking_oto_message_rejects_mistyped_response_fields() {
+    let server = MockServer::start();
+
+    server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-123","expires_in":7200}"#);
+    });
+    let send = server.mock(|when, then| {
+        when.method(POST).path("/v1.0/robot/oToMessages/batchSend");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"processQueryKey":42}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let error = client
+        .enterprise("app-key", "app-secret", "robot-code")
+        .send_oto_message("user-1", "Title", "Body")
+        .expect_err("mistyped field should fail");
+
+    assert_eq!(error.kind(), ErrorKind::Serialization);
+    send.assert();
+}
+
+#[test]
+fn blocking_session_webhook_rejects_expired_session_without_sending() {
+    let server = MockServer::start();
+
+    let send = server.mock(|when, then| {
+        when.method(POST).path("/robot/sendBySession");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok"}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+
+    let session = client.session_webhook(server.url("/robot/sendBySession?session=s"), Some(1));
+    let error = session
+        .send(&TextMessage::new("late").into())
+        .expect_err("expired session should fail");
+
+    assert_eq!(error.kind(), ErrorKind::SessionExpired);
+    send.assert_calls(0);
+}
+
+#[test]
+fn blocking_concurrent_token_refreshes_are_coalesced() {
+    let server = MockServer::start();
+
+    let get_token = server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .delay(std::time::Duration::from_millis(100))
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-123","expires_in":7200}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let enterprise = client.enterprise("app-key", "app-secret", "robot-code");
+                scope.spawn(move || enterprise.get_access_token())
+            })
+            .collect();
+        for handle in handles {
+            let token = handle
+                .join()
+                .expect("thread should finish")
+                .expect("token should be fetched");
+            assert_eq!(token, "token-123");
+        }
+    });
+
+    get_token.assert_calls(1);
+}
+
+#[test]
+fn blocking_concurrent_token_refresh_failure_is_shared() {
+    let server = MockServer::start();
+
+    let get_token = server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .delay(std::time::Duration::from_millis(100))
+            .body(r#"{"errcode":40089,"errmsg":"invalid appkey"}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .cache_access_token(false)
+        .build()
+        .expect("client should build");
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let enterprise = client.enterprise("app-key", "app-secret", "robot-code");
+                scope.spawn(move || enterprise.get_access_token())
+            })
+            .collect();
+        for handle in handles {
+            let error = handle
+                .join()
+                .expect("thread should finish")
+                .expect_err("token refresh should fail");
+            assert_eq!(error.kind(), ErrorKind::Api);
+        }
+    });
+
+    get_token.assert_calls(1);
+}
+
+#[test]
+fn blocking_invalid_token_retry_can_be_disabled() {
+    let server = MockServer::start();
+
+    let get_token = server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"fresh-token","expires_in":7200}"#);
+    });
+    let send = server.mock(|when, then| {
+        when.method(POST).path("/v1.0/robot/groupMessages/send");
+        then.status(401)
+            .header("content-type", "application/json")
+            .body(r#"{"code":"InvalidAuthentication","message":"token expired"}"#);
+    });
+
+    let store = InMemoryAccessTokenStore::new();
+    store.store(
+        "app-key",
+        CachedAccessToken::new(
+            "stale-token",
+            SystemTime::now() + Duration::from_secs(3_600),
+        ),
+    );
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .access_token_store(store.clone())
+        .retry_on_invalid_token(false)
+        .build()
+        .expect("client should build");
+
+    let error = client
+        .enterprise("app-key", "app-secret", "robot-code")
+        .send_group_message("cid-1", "title", "text")
+        .expect_err("request should fail without retry");
+
+    assert_eq!(error.kind(), ErrorKind::Auth);
+    assert!(store.get("app-key").is_some());
+    send.assert_calls(1);
+    get_token.assert_calls(0);
+}
+
+#[test]
+fn blocking_corp_enterprise_requires_suite_ticket() {
+    let server = MockServer::start();
+
+    let corp_token = server.mock(|when, then| {
+        when.method(POST).path("/v1.0/oauth2/corpAccessToken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"accessToken":"corp-token","expireIn":7200}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let suite = SuiteCredentials::new("suite-key", "suite-secret", |_: &str| None);
+
+    let error = client
+        .corp_enterprise(suite, "corp-a", "robot-code")
+        .get_access_token()
+        .expect_err("missing suite ticket should fail");
+
+    assert_eq!(error.kind(), ErrorKind::InvalidConfig);
+    corp_token.assert_calls(0);
+}
+
+#[test]
+fn blocking_oauth_refresh_surfaces_v1_errors() {
+    let server = MockServer::start();
+
+    let refresh = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1.0/oauth2/userAccessToken")
+            .json_body(serde_json::json!({
+                "clientId": "app-key",
+                "clientSecret": "app-secret",
+                "refreshToken": "expired-refresh",
+                "grantType": "refresh_token",
+            }));
+        then.status(401)
+            .header("content-type", "application/json")
+            .body(r#"{"code":"InvalidAuthentication","message":"refresh token expired","requestid":"req-1"}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let error = client
+        .oauth(AppCredentials::new("app-key", "app-secret"))
+        .refresh_user_access_token("expired-refresh")
+        .expect_err("expired refresh token should fail");
+
+    assert_eq!(error.kind(), ErrorKind::Auth);
+    refresh.assert();
+}
+
+#[test]
+fn blocking_jsapi_ticket_surfaces_api_errors() {
+    let server = MockServer::start();
+
+    let _get_token = server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+    });
+    let ticket = server.mock(|when, then| {
+        when.method(GET).path("/get_jsapi_ticket");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":90002,"errmsg":"ticket quota exceeded"}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let error = client
+        .enterprise("app-key", "app-secret", "robot-code")
+        .get_jsapi_ticket()
+        .expect_err("api error should surface");
+
+    assert_eq!(error.kind(), ErrorKind::Api);
+    ticket.assert();
+}
+
+#[test]
+fn blocking_admin_scope_surfaces_api_errors() {
+    let server = MockServer::start();
+
+    server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+    });
+    let admin_scope = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/user/get_admin_scope")
+            .query_param("access_token", "token-1")
+            .json_body(serde_json::json!({"userid": "user-1"}));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":60011,"errmsg":"no permission"}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let error = service
+        .contact_get_admin_scope(ContactGetAdminScopeRequest::new("user-1"))
+        .expect_err("api error should surface");
+    assert_eq!(error.kind(), ErrorKind::Api);
+
+    admin_scope.assert();
+}
+
+#[test]
+fn blocking_department_path_lists_ancestors_with_details() {
+    let server = MockServer::start();
+
+    server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+    });
+    let parents = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/v2/department/listparentbydept")
+            .query_param("access_token", "token-1")
+            .json_body(serde_json::json!({"dept_id": 3}));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","result":{"parent_id_list":[3,2,1]}}"#);
+    });
+    for dept_id in 1..=3 {
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/topapi/v2/department/get")
+                .json_body(serde_json::json!({ "dept_id": dept_id }));
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(serde_json::json!({
+                    "errcode": 0,
+                    "errmsg": "ok",
+                    "result": {"dept_id": dept_id, "name": format!("dept-{dept_id}")},
+                }));
+        });
+    }
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let path = service
+        .contact_department_path(3)
+        .expect("path should resolve");
+    let dept_ids: Vec<_> = path.iter().map(|department| department.dept_id).collect();
+    assert_eq!(dept_ids, [Some(3), Some(2), Some(1)]);
+    assert_eq!(path[2].name.as_deref(), Some("dept-1"));
+
+    parents.assert();
+}
+
+#[test]
+fn blocking_role_users_iter_and_removal() {
+    let server = MockServer::start();
+
+    server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+    });
+    let first_page = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/role/simplelist")
+            .query_param("access_token", "token-1")
+            .json_body(serde_json::json!({"role_id": 10, "offset": 0, "size": 2}));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","result":{"hasMore":true,"nextCursor":2,"list":[{"userid":"user-1","name":"Zhang San","manageScopes":[{"dept_id":3,"name":"R&D"}]},{"userid":"user-2"}]}}"#);
+    });
+    let second_page = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/role/simplelist")
+            .json_body(serde_json::json!({"role_id": 10, "offset": 2, "size": 2}));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","result":{"hasMore":false,"list":[{"userid":"user-3"}]}}"#);
+    });
+    let remove = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/role/removerolesforemps")
+            .json_body(serde_json::json!({"roleIds": "10", "userIds": "user-1,user-2"}));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok"}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let users = service
+        .role_list_users_iter(10, 2)
+        .collect::<Result<Vec<_>, _>>()
+        .expect("role users should list");
+    let userids: Vec<_> = users.iter().map(|user| user.userid.as_str()).collect();
+    assert_eq!(userids, ["user-1", "user-2", "user-3"]);
+    assert_eq!(users[0].manage_scopes[0].dept_id, 3);
+
+    service
+        .role_remove_roles_for_users(RoleAssignmentRequest::new([10], ["user-1", "user-2"]))
+        .expect("roles should be removed");
+
+    first_page.assert();
+    second_page.assert();
+    remove.assert();
+}
+
+#[test]
+fn blocking_external_contacts_update_delete_and_labels() {
+    let server = MockServer::start();
+
+    server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+    });
+    let update = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/extcontact/update")
+            .query_param("access_token", "token-1")
+            .json_body(serde_json::json!({
+                "contact": {
+                    "user_id": "ext-1",
+                    "name": "Partner",
+                    "follower_user_id": "user-1",
+                    "label_ids": [100, 101],
+                    "remark": "renewed",
+                }
+            }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok"}"#);
+    });
+    let delete = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/extcontact/delete")
+            .json_body(serde_json::json!({"user_id": "ext-1"}));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok"}"#);
+    });
+    let labels = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/extcontact/listlabelgroups")
+            .json_body(serde_json::json!({"offset": 0, "size": 10}));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","results":[{"name":"Level","color":-15220075,"labels":[{"id":100,"name":"VIP"}]}]}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    service
+        .contact_update_external_contact(
+            ContactUpdateExternalContactRequest::new("ext-1", "Partner", "user-1", [100, 101])
+                .remark("renewed"),
+        )
+        .expect("external contact should be updated");
+    service
+        .contact_delete_external_contact(ContactDeleteExternalContactRequest::new("ext-1"))
+        .expect("external contact should be deleted");
+    let groups = service
+        .contact_list_external_label_groups(ContactListExternalLabelGroupsRequest::new(0, 10))
+        .expect("label groups should list");
+    assert_eq!(groups[0].name.as_deref(), Some("Level"));
+    assert_eq!(groups[0].labels[0].id, 100);
+
+    update.assert();
+    delete.assert();
+    labels.assert();
+}
+
+#[test]
+fn blocking_inactive_users_iter_follows_offset() {
+    let server = MockServer::start();
+
+    server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+    });
+    let first_page = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/inactive/user/v2/get")
+            .query_param("access_token", "token-1")
+            .json_body(serde_json::json!({
+                "is_active": false,
+                "dept_ids": [3],
+                "offset": 0,
+                "size": 2,
+                "query_date": "20240105",
+            }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","result":{"has_more":true,"list":["user-1","user-2"]}}"#);
+    });
+    let second_page = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/inactive/user/v2/get")
+            .body_includes("\"offset\":2");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","result":{"has_more":false,"list":["user-3"]}}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let userids = service
+        .contact_list_inactive_users_iter(
+            ContactListInactiveUsersRequest::new("20240105", 0, 2).dept_ids([3]),
+        )
+        .collect::<Result<Vec<_>, _>>()
+        .expect("inactive users should list");
+    assert_eq!(userids, ["user-1", "user-2", "user-3"]);
+
+    first_page.assert();
+    second_page.assert();
+}
+
+#[test]
+fn blocking_approval_ids_iter_stops_after_error() {
+    let server = MockServer::start();
+
+    let _get_token = server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+    });
+    let first_page = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/processinstance/listids")
+            .body_includes("\"cursor\":0");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","result":{"list":["p1","p2"],"next_cursor":10}}"#);
+    });
+    let failing_page = server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/processinstance/listids")
+            .body_includes("\"cursor\":10");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":88,"errmsg":"system busy"}"#);
+    });
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let service = client.enterprise("app-key", "app-secret", "robot-code");
+
+    let results: Vec<_> = service
+        .approval_list_process_instance_ids_iter(ApprovalListProcessInstanceIdsRequest::new(
+            1_700_000_000_000,
+            1_700_086_400_000,
+            0,
+            10,
+        ))
+        .collect();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_deref().ok(), Some("p1"));
+    assert_eq!(results[1].as_deref().ok(), Some("p2"));
+    assert_eq!(
+        results[2].as_ref().map_err(|error| error.kind()).err(),
+        Some(ErrorKind::Api)
+    );
+    first_page.assert();
+    failing_page.assert();
+}
+
+fn mock_org_department(
+    server: &MockServer,
+    dept_id: i64,
+    parent_id: Option<i64>,
+    sub_dept_ids: &[i64],
+    userids: &[&str],
+) {
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/v2/department/get")
+            .json_body(serde_json::json!({ "dept_id": dept_id }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "errcode": 0,
+                "errmsg": "ok",
+                "result": {"dept_id": dept_id, "parent_id": parent_id, "name": format!("dept-{dept_id}")},
+            }));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/v2/department/listsubid")
+            .json_body(serde_json::json!({ "dept_id": dept_id }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "errcode": 0,
+                "errmsg": "ok",
+                "result": {"dept_id_list": sub_dept_ids},
+            }));
+    });
+    let list: Vec<_> = userids
+        .iter()
+        .map(|userid| serde_json::json!({ "userid": userid }))
+        .collect();
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/topapi/v2/user/list")
+            .body_includes(format!("\"dept_id\":{dept_id},"));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "errcode": 0,
+                "errmsg": "ok",
+                "result": {"has_more": false, "list": list},
+            }));
+    });
+}
+
+#[test]
+fn blocking_org_tree_walker_snapshots_subtree() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/gettoken");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"errcode":0,"errmsg":"ok","access_token":"token-1","expires_in":7200}"#);
+    });
+    mock_org_department(&server, 10, Some(1), &[11, 12], &["lead"]);
+    mock_org_department(&server, 11, Some(10), &[], &["u1"]);
+    mock_org_department(&server, 12, Some(10), &[], &["u1", "lead"]);
+
+    let client = BlockingClient::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let snapshot = client
+        .enterprise("app-key", "app-secret", "robot-code")
+        .org_tree_walker(10)
+        .concurrency(2)
+        .walk()
+        .expect("walk should succeed");
+
+    assert_eq!(snapshot.root_dept_id, 10);
+    assert_eq!(snapshot.department(10).expect("root").parent_id, Some(1));
+    assert_eq!(snapshot.user("lead").expect("lead").dept_ids, [10, 12]);
+    assert_eq!(snapshot.department_users(12).count(), 2);
+}
@@ -1,6 +1,6 @@
 use dingtalk_sdk::{
-    ActionCardButton, AppCredentials, ApprovalTerminateProcessInstanceRequest, Error, ErrorKind,
-    FeedCardLink,
+    ActionCardButton, ActionCardMessage, AppCredentials, ApprovalTerminateProcessInstanceRequest,
+    Error, ErrorKind, FeedCardLink, MarkdownMessage, TextMessage, WebhookMessage,
 };
 
 #[test]
@@ -42,6 +42,51 @@ fn webhook_public_types_use_expected_serde_renames() {
     );
 }
 
+#[test]
+fn webhook_message_builders_serialize_wire_format() {
+    let text: WebhookMessage = TextMessage::new("hello")
+        .at_mobiles(["13800000000"])
+        .at_all()
+        .into();
+    assert_eq!(
+        serde_json::to_value(&text).expect("text should serialize"),
+        serde_json::json!({
+            "msgtype": "text",
+            "text": {"content": "hello"},
+            "at": {"atMobiles": ["13800000000"], "isAtAll": true}
+        })
+    );
+
+    let markdown: WebhookMessage = MarkdownMessage::new("Title", "# Body").into();
+    assert_eq!(
+        serde_json::to_value(&markdown).expect("markdown should serialize"),
+        serde_json::json!({
+            "msgtype": "markdown",
+            "markdown": {"title": "Title", "text": "# Body"}
+        })
+    );
+
+    let card: WebhookMessage = ActionCardMessage::multi(
+        "Card",
+        "Pick one",
+        vec![ActionCardButton::new("Open", "https://example.com/open")],
+    )
+    .btn_orientation("1")
+    .into();
+    assert_eq!(
+        serde_json::to_value(&card).expect("action card should serialize"),
+        serde_json::json!({
+            "msgtype": "actionCard",
+            "actionCard": {
+                "title": "Card",
+                "text": "Pick one",
+                "btnOrientation": "1",
+                "btns": [{"title": "Open", "actionURL": "https://example.com/open"}]
+            }
+        })
+    );
+}
+
 #[test]
 fn api_error_helpers_report_kind_retryability_and_request_id() {
     let retryable_error = Error::Api {
@@ -0,0 +1,295 @@
+#![cfg(feature = "stream")]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use dingtalk_sdk::{
+    AppCredentials, Client, StreamAck, StreamFrame, StreamHandler, StreamSubscription,
+};
+use futures_util::{SinkExt, StreamExt};
+use httpmock::{Method::POST, MockServer};
+use serde_json::{Value, json};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+
+#[derive(Clone, Default)]
+struct RecordingHandler {
+    frames: Arc<Mutex<Vec<String>>>,
+}
+
+impl StreamHandler for RecordingHandler {
+    async fn on_callback(&self, frame: StreamFrame) -> StreamAck {
+        let message = frame.robot_message().expect("robot message");
+        self.frames
+            .lock()
+            .expect("lock")
+            .push(format!("callback:{}", message.text().unwrap_or_default()));
+        StreamAck::callback(json!({"handled": true}))
+    }
+
+    async fn on_event(&self, frame: StreamFrame) -> StreamAck {
+        self.frames.lock().expect("lock").push(format!(
+            "event:{}",
+            frame.headers.event_type.unwrap_or_default()
+        ));
+        StreamAck::success()
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn assert_ticket(request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+    assert_eq!(request.uri().query(), Some("ticket=ticket-1"));
+    Ok(response)
+}
+
+fn frame(frame_type: &str, topic: &str, message_id: &str, data: Value) -> Message {
+    Message::text(
+        json!({
+            "specVersion": "1.0",
+            "type": frame_type,
+            "headers": {
+                "messageId": message_id,
+                "topic": topic,
+                "contentType": "application/json",
+                "eventType": "user_add_org",
+            },
+            "data": data.to_string(),
+        })
+        .to_string(),
+    )
+}
+
+async fn next_ack<S>(socket: &mut S) -> Value
+where
+    S: futures_util::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+{
+    let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+        .await
+        .expect("ack should arrive")
+        .expect("socket open")
+        .expect("message");
+    serde_json::from_str(message.to_text().expect("text ack")).expect("json ack")
+}
+
+#[tokio::test]
+async fn stream_client_dispatches_frames_acks_and_reconnects() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let ws_addr = listener.local_addr().expect("addr");
+
+    let server = MockServer::start_async().await;
+    let open = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/v1.0/gateway/connections/open")
+                .body_includes("\"clientId\":\"appkey\"")
+                .body_includes("\"topic\":\"/v1.0/im/bot/messages/get\"");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({
+                    "endpoint": format!("ws://{ws_addr}/connect"),
+                    "ticket": "ticket-1",
+                }));
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let handler = RecordingHandler::default();
+    let stream = client
+        .stream(AppCredentials::new("appkey", "appsecret"))
+        .subscribe(StreamSubscription::robot_messages())
+        .subscribe(StreamSubscription::all_events())
+        .reconnect_backoff(Duration::from_millis(10), Duration::from_millis(50));
+    let task = tokio::spawn({
+        let handler = handler.clone();
+        async move { stream.run(handler).await }
+    });
+
+    let (tcp, _) = listener.accept().await.expect("accept");
+    let mut socket = tokio_tungstenite::accept_hdr_async(tcp, assert_ticket)
+        .await
+        .expect("handshake");
+
+    socket
+        .send(frame("SYSTEM", "ping", "ping-1", json!({"opaque": "x"})))
+        .await
+        .expect("send ping");
+    let ack = next_ack(&mut socket).await;
+    assert_eq!(ack["code"], 200);
+    assert_eq!(ack["headers"]["messageId"], "ping-1");
+    assert_eq!(ack["data"], r#"{"opaque":"x"}"#);
+
+    socket
+        .send(frame("EVENT", "*", "event-1", json!({"UserId": ["u1"]})))
+        .await
+        .expect("send event");
+    let ack = next_ack(&mut socket).await;
+    assert_eq!(ack["headers"]["messageId"], "event-1");
+    let data: Value = serde_json::from_str(ack["data"].as_str().expect("data")).expect("json");
+    assert_eq!(data["status"], "SUCCESS");
+
+    socket
+        .send(frame(
+            "CALLBACK",
+            "/v1.0/im/bot/messages/get",
+            "callback-1",
+            json!({"msgtype": "text", "text": {"content": "hello"}}),
+        ))
+        .await
+        .expect("send callback");
+    let ack = next_ack(&mut socket).await;
+    let data: Value = serde_json::from_str(ack["data"].as_str().expect("data")).expect("json");
+    assert_eq!(data["response"]["handled"], true);
+
+    socket
+        .send(frame("SYSTEM", "disconnect", "disconnect-1", json!({})))
+        .await
+        .expect("send disconnect");
+
+    let (tcp, _) = tokio::time::timeout(Duration::from_secs(5), listener.accept())
+        .await
+        .expect("client should reconnect")
+        .expect("accept");
+    drop(tcp);
+    task.abort();
+
+    assert_eq!(
+        *handler.frames.lock().expect("lock"),
+        ["event:user_add_org", "callback:hello"]
+    );
+    assert!(open.calls_async().await >= 2);
+}
+
+#[derive(Clone, Default)]
+struct BlockedHandler {
+    release: Arc<tokio::sync::Notify>,
+}
+
+impl StreamHandler for BlockedHandler {
+    async fn on_event(&self, _frame: StreamFrame) -> StreamAck {
+        self.release.notified().await;
+        StreamAck::success()
+    }
+}
+
+#[tokio::test]
+async fn stream_client_answers_pings_while_handlers_run() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let ws_addr = listener.local_addr().expect("addr");
+
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/v1.0/gateway/connections/open");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({
+                    "endpoint": format!("ws://{ws_addr}/connect"),
+                    "ticket": "ticket-1",
+                }));
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let handler = BlockedHandler::default();
+    let stream = client
+        .stream(AppCredentials::new("appkey", "appsecret"))
+        .subscribe(StreamSubscription::all_events());
+    let task = tokio::spawn({
+        let handler = handler.clone();
+        async move { stream.run(handler).await }
+    });
+
+    let (tcp, _) = listener.accept().await.expect("accept");
+    let mut socket = tokio_tungstenite::accept_hdr_async(tcp, assert_ticket)
+        .await
+        .expect("handshake");
+
+    socket
+        .send(frame("EVENT", "*", "event-1", json!({})))
+        .await
+        .expect("send event");
+    socket
+        .send(frame("SYSTEM", "ping", "ping-1", json!({})))
+        .await
+        .expect("send ping");
+    let ack = next_ack(&mut socket).await;
+    assert_eq!(ack["headers"]["messageId"], "ping-1");
+
+    socket
+        .send(Message::text(
+            json!({"headers": {"messageId": "bad-1"}}).to_string(),
+        ))
+        .await
+        .expect("send invalid frame");
+    let ack = next_ack(&mut socket).await;
+    assert_eq!(ack["code"], 400);
+    assert_eq!(ack["headers"]["messageId"], "bad-1");
+
+    handler.release.notify_one();
+    let ack = next_ack(&mut socket).await;
+    assert_eq!(ack["headers"]["messageId"], "event-1");
+    assert_eq!(ack["code"], 200);
+
+    task.abort();
+}
+
+#[tokio::test]
+async fn stream_client_resets_backoff_after_established_connections() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let ws_addr = listener.local_addr().expect("addr");
+
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/v1.0/gateway/connections/open");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({
+                    "endpoint": format!("ws://{ws_addr}/connect"),
+                    "ticket": "ticket-1",
+                }));
+        })
+        .await;
+
+    let client = Client::builder()
+        .webhook_base_url(server.base_url())
+        .enterprise_base_url(server.base_url())
+        .build()
+        .expect("client should build");
+    let stream = client
+        .stream(AppCredentials::new("appkey", "appsecret"))
+        .subscribe(StreamSubscription::all_events())
+        .reconnect_backoff(Duration::from_millis(100), Duration::from_secs(10));
+    let task = tokio::spawn(async move { stream.run(RecordingHandler::default()).await });
+
+    // Each connection is dropped without a close frame after the handshake.
+    // Without a reset, the fourth reconnect would wait 800ms.
+    let mut dropped_at = tokio::time::Instant::now();
+    for _ in 0..4 {
+        let (tcp, _) = tokio::time::timeout(Duration::from_secs(5), listener.accept())
+            .await
+            .expect("client should connect")
+            .expect("accept");
+        let socket = tokio_tungstenite::accept_hdr_async(tcp, assert_ticket)
+            .await
+            .expect("handshake");
+        drop(socket);
+        dropped_at = tokio::time::Instant::now();
+    }
+    let (_tcp, _) = tokio::time::timeout(Duration::from_secs(5), listener.accept())
+        .await
+        .expect("client should reconnect")
+        .expect("accept");
+    assert!(dropped_at.elapsed() < Duration::from_millis(500));
+
+    task.abort();
+}